@@ -0,0 +1,112 @@
+//! This contains the bound checks on the parameter vector
+
+use crate::{Catalog, ParameterSchema, ProblemVariant};
+
+/// One parameter that lies outside its bounds
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundViolation {
+    /// Position in the parameter vector
+    pub index: usize,
+    pub name: &'static str,
+    pub value: f64,
+    pub lower: f64,
+    pub upper: f64,
+    /// Negative when below the lower bound, positive when above the upper bound
+    pub amount: f64,
+}
+
+/// The result of checking every entry of a parameter vector against its bounds
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BoundsReport {
+    /// The signed amount by which each entry lies outside its bounds, zero when within them
+    pub amounts: Vec<f64>,
+    /// The entries with a non-zero amount
+    pub violations: Vec<BoundViolation>,
+}
+
+impl BoundsReport {
+    /// Check a parameter vector laid out for the given variant
+    ///
+    /// Every entry is checked against its bounds in the [`ParameterSchema`]. Variables whose
+    /// bounds depend on other variables are left to `Car::check_linear_constraints` and
+    /// `Car::check_nonlinear_constraints` and always report zero here.
+    pub fn from_parameters(p: &[f64], catalog: &Catalog, variant: ProblemVariant) -> Self {
+        let mut report = BoundsReport::default();
+        let schema = ParameterSchema::new(catalog, variant);
+        for (parameter, &value) in schema.parameters().iter().zip(p) {
+            let (index, name, bounds) = (parameter.index, parameter.name, parameter.bounds);
+            let amount = match bounds {
+                Some([lower, _]) if value < lower => value - lower,
+                Some([_, upper]) if value > upper => value - upper,
+                _ => 0.0,
+            };
+            if let (Some([lower, upper]), true) = (bounds, amount != 0.0) {
+                report.violations.push(BoundViolation {
+                    index,
+                    name,
+                    value,
+                    lower,
+                    upper,
+                    amount,
+                });
+            }
+            report.amounts.push(amount);
+        }
+        report
+    }
+
+    /// Whether every entry lies within its bounds
+    pub fn is_feasible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl std::fmt::Display for BoundsReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_feasible() {
+            return writeln!(f, "all parameters are within their bounds");
+        }
+        for violation in &self.violations {
+            writeln!(
+                f,
+                "[{}] {} = {} is {} {} its bounds [{}, {}]",
+                violation.index,
+                violation.name,
+                violation.value,
+                violation.amount.abs(),
+                if violation.amount < 0.0 {
+                    "below"
+                } else {
+                    "above"
+                },
+                violation.lower,
+                violation.upper
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn violations() {
+        let catalog = Catalog::builtin();
+        let mut p = crate::Car::new_with_rng(&mut StdRng::seed_from_u64(0)).get_parameter_vector();
+        assert!(
+            BoundsReport::from_parameters(&p, &catalog, ProblemVariant::ORIGINAL).is_feasible()
+        );
+
+        p[0] = 0.0;
+        p[27] = 25.0;
+        let report = BoundsReport::from_parameters(&p, &catalog, ProblemVariant::ORIGINAL);
+        assert_eq!(report.amounts.len(), 39);
+        assert!((report.amounts[0] + 0.025).abs() < 1e-12);
+        assert!((report.amounts[27] - 5.0).abs() < 1e-12);
+        assert_eq!(report.violations.len(), 2);
+        assert_eq!(report.violations[1].name, "motor_index");
+    }
+}
@@ -0,0 +1,676 @@
+//! This contains the component catalogs, parsed from the CSV files in `src/data/` or supplied by the user
+
+use std::sync::{Arc, OnceLock};
+
+use crate::components::{
+    Brake, Cell, DampingCoefficient, Inverter, Material, Motor, SpringRate, Suspension, Tire,
+    TireGrip, TorqueCurve,
+};
+use crate::constants;
+use crate::table::{Table, TableError};
+use uom::si::{
+    angular_velocity::revolution_per_minute,
+    electric_charge::ampere_hour,
+    electric_current::ampere,
+    electric_potential::volt,
+    f64::{
+        AngularVelocity, ElectricCharge, ElectricCurrent, ElectricPotential, Force, Length, Mass,
+        MassDensity, Power, Pressure, Torque, Velocity,
+    },
+    force::newton,
+    length::meter,
+    mass::kilogram,
+    mass_density::kilogram_per_cubic_meter,
+    power::watt,
+    pressure::{bar, pascal},
+    torque::newton_meter,
+    velocity::meter_per_second,
+};
+
+const TIRES: &str = include_str!("data/tires.csv");
+const BRAKES: &str = include_str!("data/brakes.csv");
+const MATERIALS: &str = include_str!("data/materials.csv");
+const MOTORS: &str = include_str!("data/motors.csv");
+const SUSPENSIONS: &str = include_str!("data/suspension.csv");
+const CELLS: &str = include_str!("data/cells.csv");
+const INVERTERS: &str = include_str!("data/inverters.csv");
+const ELECTRIC_MOTORS: &str = include_str!("data/electric_motors.csv");
+
+/// The components that the discrete design variables index into
+///
+/// The built-in catalog comes from the files in `src/data/`. Any part of it can be replaced with a
+/// user file in CSV or JSON format (chosen by file extension) using the same columns, e.g.
+/// `ID,radius,mass` for tires. A JSON file holds an array of objects keyed by those column names.
+///
+/// The cells, inverters and electric motors are only used by the electric powertrain (see
+/// [`crate::Powertrain`]). Electric motors have the columns of combustion motors plus their
+/// maximum `Speed` in rpm. A motor of either kind may be at most 0.47 m tall to fit in the space
+/// that the engine position is drawn from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Catalog {
+    tires: Vec<Tire>,
+    brakes: Vec<Brake>,
+    materials: Vec<Material>,
+    motors: Vec<Motor>,
+    suspensions: Vec<Suspension>,
+    cells: Vec<Cell>,
+    inverters: Vec<Inverter>,
+    electric_motors: Vec<Motor>,
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Catalog::builtin()
+    }
+}
+
+impl Catalog {
+    /// The catalog bundled with the crate
+    pub fn builtin() -> Self {
+        Catalog::clone(&Catalog::shared())
+    }
+
+    /// The catalog bundled with the crate, parsed on first use and shared by every car built from
+    /// it
+    pub(crate) fn shared() -> Arc<Catalog> {
+        static BUILTIN: OnceLock<Arc<Catalog>> = OnceLock::new();
+        BUILTIN
+            .get_or_init(|| Arc::new(Catalog::parse_builtin()))
+            .clone()
+    }
+
+    fn parse_builtin() -> Self {
+        let parse = |text| Table::parse_csv(text).expect("built-in catalog is valid");
+        Catalog {
+            tires: tires_from(&parse(TIRES)).expect("built-in catalog is valid"),
+            brakes: brakes_from(&parse(BRAKES)).expect("built-in catalog is valid"),
+            materials: materials_from(&parse(MATERIALS)).expect("built-in catalog is valid"),
+            motors: motors_from(&parse(MOTORS)).expect("built-in catalog is valid"),
+            suspensions: suspensions_from(&parse(SUSPENSIONS)).expect("built-in catalog is valid"),
+            cells: cells_from(&parse(CELLS)).expect("built-in catalog is valid"),
+            inverters: inverters_from(&parse(INVERTERS)).expect("built-in catalog is valid"),
+            electric_motors: electric_motors_from(&parse(ELECTRIC_MOTORS))
+                .expect("built-in catalog is valid"),
+        }
+    }
+
+    /// Replace the tires with those listed in a file
+    pub fn load_tires<P: AsRef<std::path::Path>>(&mut self, path: P) -> Result<(), CatalogError> {
+        self.tires = tires_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    /// Replace the brakes with those listed in a file
+    pub fn load_brakes<P: AsRef<std::path::Path>>(&mut self, path: P) -> Result<(), CatalogError> {
+        self.brakes = brakes_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    /// Replace the materials with those listed in a file
+    pub fn load_materials<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), CatalogError> {
+        self.materials = materials_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    /// Replace the motors with those listed in a file
+    pub fn load_motors<P: AsRef<std::path::Path>>(&mut self, path: P) -> Result<(), CatalogError> {
+        self.motors = motors_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    /// Replace the suspensions with those listed in a file
+    pub fn load_suspensions<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), CatalogError> {
+        self.suspensions = suspensions_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    /// Replace the battery cells with those listed in a file
+    pub fn load_cells<P: AsRef<std::path::Path>>(&mut self, path: P) -> Result<(), CatalogError> {
+        self.cells = cells_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    /// Replace the inverters with those listed in a file
+    pub fn load_inverters<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), CatalogError> {
+        self.inverters = inverters_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    /// Replace the electric motors with those listed in a file
+    pub fn load_electric_motors<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<(), CatalogError> {
+        self.electric_motors = electric_motors_from(&Table::read(path.as_ref())?)?;
+        Ok(())
+    }
+
+    pub fn tires(&self) -> &[Tire] {
+        &self.tires
+    }
+
+    pub fn brakes(&self) -> &[Brake] {
+        &self.brakes
+    }
+
+    pub fn materials(&self) -> &[Material] {
+        &self.materials
+    }
+
+    pub fn motors(&self) -> &[Motor] {
+        &self.motors
+    }
+
+    pub fn suspensions(&self) -> &[Suspension] {
+        &self.suspensions
+    }
+
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    pub fn inverters(&self) -> &[Inverter] {
+        &self.inverters
+    }
+
+    pub fn electric_motors(&self) -> &[Motor] {
+        &self.electric_motors
+    }
+}
+
+/// The reasons a catalog file can be rejected
+#[derive(Debug)]
+pub enum CatalogError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file extension is neither `.csv` nor `.json`
+    UnsupportedFormat(String),
+    /// The file is not valid JSON, or not an array of flat objects
+    Json(String),
+    /// The file lists no components
+    Empty,
+    /// A row has a different number of cells than the header
+    RowLength { row: usize },
+    /// A required column is absent
+    MissingColumn(String),
+    /// A cell that should hold a number does not
+    InvalidNumber {
+        row: usize,
+        column: String,
+        value: String,
+    },
+    /// A physical quantity is zero, negative or not finite
+    NonPositive { row: usize, column: String },
+    /// A quantity that may be zero is negative or not finite
+    Negative { row: usize, column: String },
+    /// A dimension is larger than the design space leaves room for
+    TooLarge {
+        row: usize,
+        column: String,
+        limit: f64,
+    },
+}
+
+impl std::fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogError::Io(error) => write!(f, "could not read catalog: {}", error),
+            CatalogError::UnsupportedFormat(path) => {
+                write!(f, "`{}` is not a .csv or .json file", path)
+            }
+            CatalogError::Json(message) => write!(f, "invalid JSON catalog: {}", message),
+            CatalogError::Empty => write!(f, "catalog has no entries"),
+            CatalogError::RowLength { row } => {
+                write!(f, "row {} has the wrong number of cells", row)
+            }
+            CatalogError::MissingColumn(column) => {
+                write!(f, "catalog has no column named `{}`", column)
+            }
+            CatalogError::InvalidNumber { row, column, value } => write!(
+                f,
+                "`{}` in row {}, column `{}` is not a number",
+                value, row, column
+            ),
+            CatalogError::NonPositive { row, column } => {
+                write!(f, "row {}, column `{}` must be positive", row, column)
+            }
+            CatalogError::Negative { row, column } => {
+                write!(f, "row {}, column `{}` must not be negative", row, column)
+            }
+            CatalogError::TooLarge { row, column, limit } => write!(
+                f,
+                "row {}, column `{}` must be at most {} to fit in the car",
+                row, column, limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CatalogError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CatalogError {
+    fn from(error: std::io::Error) -> Self {
+        CatalogError::Io(error)
+    }
+}
+
+impl From<TableError> for CatalogError {
+    fn from(error: TableError) -> Self {
+        match error {
+            TableError::Io(error) => CatalogError::Io(error),
+            TableError::UnsupportedFormat(path) => CatalogError::UnsupportedFormat(path),
+            TableError::Json(message) => CatalogError::Json(message),
+            TableError::RowLength { row } => CatalogError::RowLength { row },
+        }
+    }
+}
+
+/// Tires may describe their grip in the optional columns `friction`, `nominal_load` (N),
+/// `load_sensitivity`, `optimal_pressure` (bar) and `pressure_sensitivity`. Any that are left out
+/// take the values of [`TireGrip::default`], and the two sensitivities may be 0 to switch them
+/// off. The optional `stiffness` column gives the vertical stiffness in N/m.
+fn tires_from(table: &Table) -> Result<Vec<Tire>, CatalogError> {
+    table.records(|row| {
+        let default = TireGrip::default();
+        let default_stiffness = Tire::default().stiffness;
+        let grip = TireGrip {
+            friction: table
+                .optional_positive(row, "friction")?
+                .unwrap_or(default.friction),
+            nominal_load: table
+                .optional_positive(row, "nominal_load")?
+                .map_or(default.nominal_load, Force::new::<newton>),
+            load_sensitivity: table
+                .optional_non_negative(row, "load_sensitivity")?
+                .unwrap_or(default.load_sensitivity),
+            optimal_pressure: table
+                .optional_positive(row, "optimal_pressure")?
+                .map_or(default.optimal_pressure, Pressure::new::<bar>),
+            pressure_sensitivity: table
+                .optional_non_negative(row, "pressure_sensitivity")?
+                .unwrap_or(default.pressure_sensitivity),
+        };
+        Ok(Tire {
+            id: table.text(row, "ID")?,
+            radius: Length::new::<meter>(table.positive(row, "radius")?),
+            mass: Mass::new::<kilogram>(table.positive(row, "mass")?),
+            grip,
+            stiffness: table
+                .optional_positive(row, "stiffness")?
+                .map_or(default_stiffness, |stiffness| {
+                    Force::new::<newton>(stiffness) / Length::new::<meter>(1.0)
+                }),
+        })
+    })
+}
+
+fn brakes_from(table: &Table) -> Result<Vec<Brake>, CatalogError> {
+    table.records(|row| {
+        Ok(Brake {
+            id: table.text(row, "brakeID")?,
+            density: MassDensity::new::<kilogram_per_cubic_meter>(table.positive(row, "qbrk")?),
+            length: Length::new::<meter>(table.positive(row, "lbrk")?),
+            height: Length::new::<meter>(table.positive(row, "hbrk")?),
+            width: Length::new::<meter>(table.positive(row, "wbrk")?),
+            thickness: Length::new::<meter>(table.positive(row, "tbrk")?),
+            radius: Length::new::<meter>(table.positive(row, "rbrk")?),
+        })
+    })
+}
+
+fn materials_from(table: &Table) -> Result<Vec<Material>, CatalogError> {
+    table.records(|row| {
+        Ok(Material {
+            name: table.text(row, "Material")?,
+            code: table.text(row, "Code")?,
+            density: MassDensity::new::<kilogram_per_cubic_meter>(table.positive(row, "q")?),
+            modulus: Pressure::new::<pascal>(table.positive(row, "E")?),
+        })
+    })
+}
+
+/// Motors may list their torque curve in an optional `Torque Curve` column as `rpm:N·m` pairs
+/// separated by semicolons, e.g. `2500:12.4;3600:11.0`. Otherwise the curve is derived from the
+/// peak power and torque.
+fn motors_from(table: &Table) -> Result<Vec<Motor>, CatalogError> {
+    table.records(|row| {
+        let power = Power::new::<watt>(table.positive(row, "Power")?);
+        let torque = Torque::new::<newton_meter>(table.positive(row, "Torque")?);
+        let torque_curve = match table.optional_text(row, "Torque Curve") {
+            Some(curve) if !curve.trim().is_empty() => {
+                torque_curve_from(&curve).ok_or_else(|| CatalogError::InvalidNumber {
+                    row,
+                    column: "Torque Curve".to_owned(),
+                    value: curve.clone(),
+                })?
+            }
+            _ => TorqueCurve::from_peaks(power, torque),
+        };
+        Ok(Motor {
+            id: table.text(row, "ID")?,
+            name: table.text(row, "Name")?,
+            length: Length::new::<meter>(table.positive(row, "Length")?),
+            width: Length::new::<meter>(table.positive(row, "Width")?),
+            height: motor_height(table, row)?,
+            mass: Mass::new::<kilogram>(table.positive(row, "Mass")?),
+            power,
+            torque,
+            torque_curve,
+        })
+    })
+}
+
+/// The height of a motor, which must leave it room between the ground clearance and the top of
+/// the engine bay
+fn motor_height(table: &Table, row: usize) -> Result<Length, CatalogError> {
+    let height = table.positive(row, "Height")?;
+    if height > constants::MAX_MOTOR_HEIGHT {
+        return Err(CatalogError::TooLarge {
+            row,
+            column: "Height".to_owned(),
+            limit: constants::MAX_MOTOR_HEIGHT,
+        });
+    }
+    Ok(Length::new::<meter>(height))
+}
+
+/// Parse `rpm:N·m` pairs separated by semicolons, in increasing order of speed
+fn torque_curve_from(text: &str) -> Option<TorqueCurve> {
+    let mut points: Vec<(AngularVelocity, Torque)> = vec![];
+    for pair in text.split(';') {
+        let mut numbers = pair.split(':').map(|number| number.trim().parse::<f64>());
+        let (speed, torque) = match (numbers.next(), numbers.next(), numbers.next()) {
+            (Some(Ok(speed)), Some(Ok(torque)), None) => (speed, torque),
+            _ => return None,
+        };
+        let increasing = match points.last() {
+            Some((last, _)) => speed > last.get::<revolution_per_minute>(),
+            None => true,
+        };
+        if !(speed.is_finite() && torque.is_finite() && speed >= 0.0 && torque >= 0.0 && increasing)
+        {
+            return None;
+        }
+        points.push((
+            AngularVelocity::new::<revolution_per_minute>(speed),
+            Torque::new::<newton_meter>(torque),
+        ));
+    }
+    Some(TorqueCurve::new(points))
+}
+
+/// Electric motors have the columns of combustion motors plus their maximum `Speed` in rpm, from
+/// which the torque curve is derived
+fn electric_motors_from(table: &Table) -> Result<Vec<Motor>, CatalogError> {
+    table.records(|row| {
+        let power = Power::new::<watt>(table.positive(row, "Power")?);
+        let torque = Torque::new::<newton_meter>(table.positive(row, "Torque")?);
+        let speed = AngularVelocity::new::<revolution_per_minute>(table.positive(row, "Speed")?);
+        Ok(Motor {
+            id: table.text(row, "ID")?,
+            name: table.text(row, "Name")?,
+            length: Length::new::<meter>(table.positive(row, "Length")?),
+            width: Length::new::<meter>(table.positive(row, "Width")?),
+            height: motor_height(table, row)?,
+            mass: Mass::new::<kilogram>(table.positive(row, "Mass")?),
+            power,
+            torque,
+            torque_curve: TorqueCurve::from_limits(power, torque, speed),
+        })
+    })
+}
+
+fn cells_from(table: &Table) -> Result<Vec<Cell>, CatalogError> {
+    table.records(|row| {
+        Ok(Cell {
+            id: table.text(row, "ID")?,
+            name: table.text(row, "Name")?,
+            voltage: ElectricPotential::new::<volt>(table.positive(row, "Voltage")?),
+            capacity: ElectricCharge::new::<ampere_hour>(table.positive(row, "Capacity")?),
+            mass: Mass::new::<kilogram>(table.positive(row, "Mass")?),
+            current: ElectricCurrent::new::<ampere>(table.positive(row, "Current")?),
+        })
+    })
+}
+
+fn inverters_from(table: &Table) -> Result<Vec<Inverter>, CatalogError> {
+    table.records(|row| {
+        let efficiency = table.positive(row, "Efficiency")?;
+        if efficiency > 1.0 {
+            return Err(CatalogError::InvalidNumber {
+                row,
+                column: "Efficiency".to_owned(),
+                value: efficiency.to_string(),
+            });
+        }
+        Ok(Inverter {
+            id: table.text(row, "ID")?,
+            name: table.text(row, "Name")?,
+            voltage: ElectricPotential::new::<volt>(table.positive(row, "Voltage")?),
+            current: ElectricCurrent::new::<ampere>(table.positive(row, "Current")?),
+            mass: Mass::new::<kilogram>(table.positive(row, "Mass")?),
+            efficiency,
+        })
+    })
+}
+
+/// Spring constants are given in N/m and damping coefficients in N·s/m.
+fn suspensions_from(table: &Table) -> Result<Vec<Suspension>, CatalogError> {
+    table.records(|row| {
+        let spring = |column| -> Result<SpringRate, CatalogError> {
+            Ok(Force::new::<newton>(table.positive(row, column)?) / Length::new::<meter>(1.0))
+        };
+        let damper = |column| -> Result<DampingCoefficient, CatalogError> {
+            Ok(Force::new::<newton>(table.positive(row, column)?)
+                / Velocity::new::<meter_per_second>(1.0))
+        };
+        Ok(Suspension {
+            id: table.text(row, "id")?,
+            rear_spring_constant: spring("krsp")?,
+            rear_damping_coefficient: damper("crsp")?,
+            rear_mass: Mass::new::<kilogram>(table.positive(row, "mrsp")?),
+            front_spring_constant: spring("kfsp")?,
+            front_damping_coefficient: damper("cfsp")?,
+            front_mass: Mass::new::<kilogram>(table.positive(row, "mfsp")?),
+        })
+    })
+}
+
+/// Reading the cells of a catalog file, with the errors of [`CatalogError`]
+impl Table {
+    /// Build one record per row, rejecting empty tables
+    fn records<T, F>(&self, record: F) -> Result<Vec<T>, CatalogError>
+    where
+        F: Fn(usize) -> Result<T, CatalogError>,
+    {
+        if self.is_empty() {
+            return Err(CatalogError::Empty);
+        }
+        (0..self.len()).map(record).collect()
+    }
+
+    fn text(&self, row: usize, column: &str) -> Result<String, CatalogError> {
+        self.cell(row, column)
+            .map(str::to_owned)
+            .ok_or_else(|| CatalogError::MissingColumn(column.to_owned()))
+    }
+
+    /// The cell in a column that catalogs may leave out
+    fn optional_text(&self, row: usize, column: &str) -> Option<String> {
+        self.text(row, column).ok()
+    }
+
+    /// The number in a column that catalogs may leave out, checked as in [`Table::positive`]
+    fn optional_positive(&self, row: usize, column: &str) -> Result<Option<f64>, CatalogError> {
+        match self.optional_text(row, column) {
+            Some(value) if !value.trim().is_empty() => self.positive(row, column).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// The number in a column that catalogs may leave out, checked as in
+    /// [`Table::non_negative`]
+    fn optional_non_negative(&self, row: usize, column: &str) -> Result<Option<f64>, CatalogError> {
+        match self.optional_text(row, column) {
+            Some(value) if !value.trim().is_empty() => self.non_negative(row, column).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    fn positive(&self, row: usize, column: &str) -> Result<f64, CatalogError> {
+        let number = self.number(row, column)?;
+        if number.is_finite() && number > 0.0 {
+            Ok(number)
+        } else {
+            Err(CatalogError::NonPositive {
+                row,
+                column: column.to_owned(),
+            })
+        }
+    }
+
+    /// A number that may be zero, such as a sensitivity that a catalog switches off
+    fn non_negative(&self, row: usize, column: &str) -> Result<f64, CatalogError> {
+        let number = self.number(row, column)?;
+        if number.is_finite() && number >= 0.0 {
+            Ok(number)
+        } else {
+            Err(CatalogError::Negative {
+                row,
+                column: column.to_owned(),
+            })
+        }
+    }
+
+    fn number(&self, row: usize, column: &str) -> Result<f64, CatalogError> {
+        let value = self.text(row, column)?;
+        value
+            .trim()
+            .parse()
+            .map_err(|_| CatalogError::InvalidNumber {
+                row,
+                column: column.to_owned(),
+                value: value.clone(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin() {
+        let catalog = Catalog::builtin();
+        assert_eq!(catalog.tires().len(), 7);
+        assert_eq!(catalog.brakes().len(), 34);
+        assert_eq!(catalog.materials().len(), 13);
+        assert_eq!(catalog.motors().len(), 21);
+        assert_eq!(catalog.suspensions().len(), 5);
+        assert_eq!(catalog.cells().len(), 6);
+        assert_eq!(catalog.inverters().len(), 5);
+        assert_eq!(catalog.electric_motors().len(), 5);
+        assert_eq!(catalog.materials()[11].name, "Cast Iron, grade 20");
+        assert_eq!(catalog.brakes()[0].radius, Length::new::<meter>(0.03));
+    }
+
+    #[test]
+    fn user_files() {
+        let csv = Table::parse_csv("ID,radius,mass\nX1,0.25,4.2\n").unwrap();
+        let json = Table::parse_json(r#"[{"ID": "X1", "radius": 0.25, "mass": 4.2}]"#).unwrap();
+        assert_eq!(tires_from(&csv).unwrap(), tires_from(&json).unwrap());
+        assert_eq!(tires_from(&csv).unwrap()[0].grip, TireGrip::default());
+        let grippy = Table::parse_csv("ID,radius,mass,friction\nX1,0.25,4.2,1.8\n").unwrap();
+        assert_eq!(tires_from(&grippy).unwrap()[0].grip.friction, 1.8);
+        let stiff = Table::parse_csv("ID,radius,mass,stiffness\nX1,0.25,4.2,150000\n").unwrap();
+        assert_eq!(tires_from(&stiff).unwrap()[0].stiffness.value, 150_000.0);
+        let sparse = Table::parse_json(
+            r#"[
+                {"ID": "X1", "radius": 0.25, "mass": 4.2, "friction": 1.8, "load_sensitivity": 0},
+                {"ID": "X2", "radius": 0.3, "mass": 5.0, "pressure_sensitivity": 0.0}
+            ]"#,
+        )
+        .unwrap();
+        let tires = tires_from(&sparse).unwrap();
+        assert_eq!(tires[0].grip.friction, 1.8);
+        assert_eq!(tires[1].grip, TireGrip::default());
+        let negative_sensitivity =
+            Table::parse_csv("ID,radius,mass,load_sensitivity\nX1,0.25,4.2,-0.1\n");
+        assert!(matches!(
+            tires_from(&negative_sensitivity.unwrap()),
+            Err(CatalogError::Negative { row: 0, .. })
+        ));
+
+        let negative = Table::parse_csv("ID,radius,mass\nX1,-0.25,4.2\n").unwrap();
+        assert!(matches!(
+            tires_from(&negative),
+            Err(CatalogError::NonPositive { row: 0, .. })
+        ));
+        let missing = Table::parse_csv("ID,radius\nX1,0.25\n").unwrap();
+        assert!(matches!(
+            tires_from(&missing),
+            Err(CatalogError::MissingColumn(_))
+        ));
+
+        let header = "ID,Name,Length,Width,Height,Mass,Power,Torque";
+        let derived = Table::parse_csv(&format!("{}\nX1,X,0.3,0.3,0.3,20,6000,20\n", header));
+        let motor = &motors_from(&derived.unwrap()).unwrap()[0];
+        assert_eq!(
+            motor.torque_curve,
+            TorqueCurve::from_peaks(motor.power, motor.torque)
+        );
+        let listed = Table::parse_csv(&format!(
+            "{},Torque Curve\nX1,X,0.3,0.3,0.3,20,6000,20,1000:15;3000:20\n",
+            header
+        ));
+        let curve = &motors_from(&listed.unwrap()).unwrap()[0].torque_curve;
+        let torque = curve.torque_at(AngularVelocity::new::<revolution_per_minute>(2000.0));
+        assert!((torque.get::<newton_meter>() - 17.5).abs() < 1e-9);
+        let unordered = Table::parse_csv(&format!(
+            "{},Torque Curve\nX1,X,0.3,0.3,0.3,20,6000,20,3000:20;1000:15\n",
+            header
+        ));
+        assert!(matches!(
+            motors_from(&unordered.unwrap()),
+            Err(CatalogError::InvalidNumber { row: 0, .. })
+        ));
+        assert!(matches!(
+            Catalog::builtin().load_tires("tires.txt"),
+            Err(CatalogError::UnsupportedFormat(_))
+        ));
+
+        // A motor too tall for the engine bay is rejected rather than left to break sampling
+        let path = std::env::temp_dir().join(format!("sae-tall-motors-{}.csv", std::process::id()));
+        std::fs::write(
+            &path,
+            format!(
+                "{}\nX1,X,0.3,0.3,0.47,20,6000,20\nX2,X,0.3,0.3,0.48,20,6000,20\n",
+                header
+            ),
+        )
+        .unwrap();
+        let mut catalog = Catalog::builtin();
+        let loaded = catalog.load_motors(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(loaded, Err(CatalogError::TooLarge { row: 1, .. })));
+        let fitting = Table::parse_csv(&format!("{}\nX1,X,0.3,0.3,0.47,20,6000,20\n", header));
+        catalog.motors = motors_from(&fitting.unwrap()).unwrap();
+        crate::Car::new_with_catalog(catalog);
+    }
+}
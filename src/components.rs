@@ -0,0 +1,302 @@
+//! This contains the components that can be selected from the catalogs
+
+use std::ops::Div;
+
+use uom::si::{
+    angular_velocity::radian_per_second,
+    f64::{
+        AngularVelocity, ElectricCharge, ElectricCurrent, ElectricPotential, Energy, Force, Length,
+        Mass, MassDensity, Power, Pressure, Torque, Velocity,
+    },
+    force::newton,
+    length::meter,
+    pressure::bar,
+    torque::newton_meter,
+};
+
+/// The speed above the peak-power speed at which a derived torque curve is cut off
+const REDLINE_FACTOR: f64 = 1.25;
+
+/// The stiffness of a spring, in N/m when read in SI units
+///
+/// uom has no quantity for a linear spring rate, so this is the type of a force divided by a
+/// length.
+pub type SpringRate = <Force as Div<Length>>::Output;
+
+/// The damping coefficient of a damper, in N·s/m when read in SI units
+///
+/// uom has no quantity for a linear damping coefficient, so this is the type of a force divided
+/// by a velocity.
+pub type DampingCoefficient = <Force as Div<Velocity>>::Output;
+
+/// The vertical stiffness of a tire whose catalog entry does not give one, in N/m
+const DEFAULT_TIRE_STIFFNESS: f64 = 100_000.0;
+
+/// A tire, identified by its catalog ID (e.g. "T1")
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tire {
+    pub id: String,
+    pub radius: Length,
+    pub mass: Mass,
+    pub grip: TireGrip,
+    /// Vertical stiffness, like the spring constants of [`Suspension`]
+    pub stiffness: SpringRate,
+}
+
+impl Default for Tire {
+    fn default() -> Self {
+        Tire {
+            id: String::default(),
+            radius: Length::default(),
+            mass: Mass::default(),
+            grip: TireGrip::default(),
+            stiffness: Force::new::<newton>(DEFAULT_TIRE_STIFFNESS) / Length::new::<meter>(1.0),
+        }
+    }
+}
+
+/// The friction coefficient of a tire as a function of its normal load and inflation pressure
+///
+/// The coefficient falls off with load as `friction * (load / nominal_load)^-load_sensitivity`,
+/// and is scaled by `1 - pressure_sensitivity * ((pressure - optimal_pressure) /
+/// optimal_pressure)^2`. The default is the fixed coefficient of 1.6 of the original problem.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TireGrip {
+    /// The friction coefficient at the nominal load and optimal pressure
+    pub friction: f64,
+    pub nominal_load: Force,
+    pub load_sensitivity: f64,
+    pub optimal_pressure: Pressure,
+    pub pressure_sensitivity: f64,
+}
+
+impl Default for TireGrip {
+    fn default() -> Self {
+        TireGrip {
+            friction: 1.6,
+            nominal_load: Force::new::<newton>(700.0),
+            load_sensitivity: 0.0,
+            optimal_pressure: Pressure::new::<bar>(0.9),
+            pressure_sensitivity: 0.0,
+        }
+    }
+}
+
+impl TireGrip {
+    /// The friction coefficient under the given normal load and inflation pressure
+    pub fn friction(&self, load: Force, pressure: Pressure) -> f64 {
+        let load = (load / self.nominal_load).value.max(1e-6);
+        let deviation = ((pressure - self.optimal_pressure) / self.optimal_pressure).value;
+        let pressure_factor = (1.0 - self.pressure_sensitivity * deviation * deviation).max(0.0);
+        self.friction * load.powf(-self.load_sensitivity) * pressure_factor
+    }
+}
+
+/// A brake, identified by its catalog ID (e.g. "B15")
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Brake {
+    pub id: String,
+    pub density: MassDensity,
+    pub length: Length,
+    pub height: Length,
+    pub width: Length,
+    pub thickness: Length,
+    pub radius: Length,
+}
+
+/// A structural material, identified by its catalog code (e.g. "HDPE")
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Material {
+    pub name: String,
+    pub code: String,
+    pub density: MassDensity,
+    pub modulus: Pressure,
+}
+
+/// A motor, identified by its catalog ID (e.g. "M1") and model name (e.g. "GX200")
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Motor {
+    pub id: String,
+    pub name: String,
+    pub length: Length,
+    pub width: Length,
+    pub height: Length,
+    pub mass: Mass,
+    /// Peak power
+    pub power: Power,
+    /// Peak torque
+    pub torque: Torque,
+    /// The torque available at each engine speed
+    pub torque_curve: TorqueCurve,
+}
+
+/// Torque against engine speed, interpolated linearly between points
+///
+/// Below the first point the torque of the first point is available, as when slipping a clutch
+/// at launch, and above the last point there is none.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TorqueCurve {
+    /// Pairs of engine speed and torque, in increasing order of speed
+    pub points: Vec<(AngularVelocity, Torque)>,
+}
+
+impl TorqueCurve {
+    pub fn new(points: Vec<(AngularVelocity, Torque)>) -> Self {
+        TorqueCurve { points }
+    }
+
+    /// A curve with the peak torque up to the speed at which it gives the peak power, then the
+    /// peak power up to a redline 25% above that speed
+    pub fn from_peaks(power: Power, torque: Torque) -> Self {
+        let base = power.value / torque.get::<newton_meter>();
+        TorqueCurve::from_limits(
+            power,
+            torque,
+            AngularVelocity::new::<radian_per_second>(REDLINE_FACTOR * base),
+        )
+    }
+
+    /// A curve with the peak torque up to the speed at which it gives the peak power, then the
+    /// peak power up to the given maximum speed, as for an electric motor
+    pub fn from_limits(power: Power, torque: Torque, max_speed: AngularVelocity) -> Self {
+        let torque = torque.get::<newton_meter>();
+        let max_speed = max_speed.get::<radian_per_second>();
+        let base = (power.value / torque).min(max_speed);
+        let mut points = vec![(0.0, torque)];
+        for i in 0..=4 {
+            let speed = base + (max_speed - base) * i as f64 / 4.0;
+            points.push((speed, torque.min(power.value / speed)));
+        }
+        points.dedup_by(|a, b| a.0 == b.0);
+        TorqueCurve::new(
+            points
+                .into_iter()
+                .map(|(speed, torque)| {
+                    (
+                        AngularVelocity::new::<radian_per_second>(speed),
+                        Torque::new::<newton_meter>(torque),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// The torque available at the given engine speed
+    pub fn torque_at(&self, speed: AngularVelocity) -> Torque {
+        let speed = speed.get::<radian_per_second>();
+        let points: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .map(|(speed, torque)| {
+                (
+                    speed.get::<radian_per_second>(),
+                    torque.get::<newton_meter>(),
+                )
+            })
+            .collect();
+        let torque = match (points.first(), points.last()) {
+            (Some(&(low, torque)), _) if speed <= low => torque,
+            (_, Some(&(high, _))) if speed > high => 0.0,
+            _ => points
+                .windows(2)
+                .find(|pair| speed <= pair[1].0)
+                .map(|pair| {
+                    let fraction = (speed - pair[0].0) / (pair[1].0 - pair[0].0);
+                    pair[0].1 + fraction * (pair[1].1 - pair[0].1)
+                })
+                .unwrap_or_default(),
+        };
+        Torque::new::<newton_meter>(torque)
+    }
+
+    /// The highest engine speed on the curve
+    pub fn max_speed(&self) -> AngularVelocity {
+        self.points
+            .last()
+            .map(|(speed, _)| *speed)
+            .unwrap_or_default()
+    }
+}
+
+/// A suspension set, identified by its catalog ID (e.g. "S1")
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Suspension {
+    pub id: String,
+    pub rear_spring_constant: SpringRate,
+    pub rear_damping_coefficient: DampingCoefficient,
+    pub rear_mass: Mass,
+    pub front_spring_constant: SpringRate,
+    pub front_damping_coefficient: DampingCoefficient,
+    pub front_mass: Mass,
+}
+
+/// A battery cell, identified by its catalog ID (e.g. "C1")
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cell {
+    pub id: String,
+    pub name: String,
+    /// Nominal voltage
+    pub voltage: ElectricPotential,
+    pub capacity: ElectricCharge,
+    pub mass: Mass,
+    /// Maximum continuous discharge current
+    pub current: ElectricCurrent,
+}
+
+/// A motor controller, identified by its catalog ID (e.g. "I1")
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Inverter {
+    pub id: String,
+    pub name: String,
+    /// Maximum DC bus voltage
+    pub voltage: ElectricPotential,
+    /// Maximum DC bus current
+    pub current: ElectricCurrent,
+    pub mass: Mass,
+    /// The fraction of battery power that reaches the motor shaft
+    pub efficiency: f64,
+}
+
+/// An accumulator of identical cells, with `series` cells per string and `parallel` strings
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatteryPack {
+    pub cell: Cell,
+    pub series: usize,
+    pub parallel: usize,
+}
+
+impl BatteryPack {
+    /// The number of cells in the pack
+    pub fn cells(&self) -> usize {
+        self.series * self.parallel
+    }
+
+    /// Nominal voltage
+    pub fn voltage(&self) -> ElectricPotential {
+        self.cell.voltage * self.series as f64
+    }
+
+    pub fn capacity(&self) -> ElectricCharge {
+        self.cell.capacity * self.parallel as f64
+    }
+
+    /// The energy stored when full, at nominal voltage
+    pub fn energy(&self) -> Energy {
+        self.voltage() * self.capacity()
+    }
+
+    /// Maximum continuous discharge current
+    pub fn current(&self) -> ElectricCurrent {
+        self.cell.current * self.parallel as f64
+    }
+
+    /// Maximum continuous discharge power
+    pub fn power(&self) -> Power {
+        self.voltage() * self.current()
+    }
+
+    /// The mass of the cells alone, without container, wiring or battery management
+    pub fn cell_mass(&self) -> Mass {
+        self.cell.mass * self.cells() as f64
+    }
+}
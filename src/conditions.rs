@@ -0,0 +1,50 @@
+//! This contains the conditions under which a car is evaluated
+
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    f64::{Acceleration, Length, MassDensity, Pressure, Velocity},
+    length::meter,
+    mass_density::kilogram_per_cubic_meter,
+    pressure::pascal,
+    velocity::meter_per_second,
+};
+
+/// The environment and test settings used when evaluating the objectives
+///
+/// The default is the original problem: sea-level air, a 9 m skidpad radius and a speed of
+/// 26.8 m/s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OperatingConditions {
+    /// The speed at which aerodynamic, acceleration and braking objectives are evaluated
+    pub vehicle_speed: Velocity,
+    /// The fraction of engine power that reaches the rear wheels
+    pub drivetrain_efficiency: f64,
+    pub air_density: MassDensity,
+    /// The radius of the skidpad used for the corner velocity
+    pub skidpad_radius: Length,
+    /// The hydraulic pressure applied to the brakes
+    pub brake_pressure: Pressure,
+    /// The drag coefficient of the cabin
+    pub cabin_drag_coefficient: f64,
+    pub gravity: Acceleration,
+    /// The displacement of the springs when computing suspension forces
+    pub suspension_displacement: Length,
+    /// The velocity of the dampers when computing suspension forces
+    pub suspension_velocity: Velocity,
+}
+
+impl Default for OperatingConditions {
+    fn default() -> Self {
+        OperatingConditions {
+            vehicle_speed: Velocity::new::<meter_per_second>(26.8),
+            drivetrain_efficiency: 0.9,
+            air_density: MassDensity::new::<kilogram_per_cubic_meter>(1.225),
+            skidpad_radius: Length::new::<meter>(9.0),
+            brake_pressure: Pressure::new::<pascal>(10_000_000.0),
+            cabin_drag_coefficient: 0.04,
+            gravity: Acceleration::new::<meter_per_second_squared>(9.81),
+            suspension_displacement: Length::new::<meter>(0.05),
+            suspension_velocity: Velocity::new::<meter_per_second>(0.025),
+        }
+    }
+}
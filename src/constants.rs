@@ -20,107 +20,56 @@ pub(crate) const CONST_BOUNDS: [[f64; 2]; 19] = [
     [0.2, 0.5],
 ];
 
-pub(crate) fn get_tires() -> std::collections::HashMap<usize, std::collections::HashMap<String, f64>>
-{
-    let mut tires: std::collections::HashMap<usize, std::collections::HashMap<String, f64>> =
-        std::collections::HashMap::new();
-    tires.insert(0, build_tire(0.2286, 3.636));
-    tires.insert(1, build_tire(0.22987, 4.091));
-    tires.insert(2, build_tire(0.23241, 4.545));
-    tires.insert(3, build_tire(0.24638, 4.545));
-    tires.insert(4, build_tire(0.24765, 5.0));
-    tires.insert(5, build_tire(0.26162, 5.455));
-    tires.insert(6, build_tire(0.2667, 5.0));
-    tires
-}
+/// The tallest motor that fits between 0.03 m and 0.5 m above the ground, the range of its
+/// center's height less half its own height at each end
+pub(crate) const MAX_MOTOR_HEIGHT: f64 = 0.47;
 
-fn build_tire(radius: f64, mass: f64) -> std::collections::HashMap<String, f64> {
-    [("radius".to_owned(), radius), ("mass".to_owned(), mass)]
-        .iter()
-        .cloned()
-        .collect()
-}
+/// The bounds of the final drive ratio when it is a design variable
+///
+/// At the upper bound every catalog motor on every catalog tire is still below its redline at the
+/// default operating speed of 26.8 m/s, the tightest being the GCV520 (338 rad/s) on the smallest
+/// tire.
+pub(crate) const FINAL_DRIVE_BOUNDS: [f64; 2] = [1.4, 2.85];
 
-pub(crate) fn get_brakes(
-) -> std::collections::HashMap<usize, std::collections::HashMap<String, f64>> {
-    let mut brakes: std::collections::HashMap<usize, std::collections::HashMap<String, f64>> =
-        std::collections::HashMap::new();
+/// The final drive ratio when it is not a design variable, the upper bound of
+/// [`FINAL_DRIVE_BOUNDS`], which puts the catalog motors between 285 and 335 rad/s at the
+/// default operating speed, close to their peak power
+pub(crate) const FINAL_DRIVE_RATIO: f64 = 2.85;
 
-    brakes.insert(0, build_brake(0.5, 0.104, 0.0325, 0.016, 0.32, 0.03));
-    brakes.insert(1, build_brake(0.4, 0.1, 0.0425, 0.02, 0.35, 0.025));
-    brakes.insert(2, build_brake(0.5, 0.1135, 0.04, 0.016, 0.35, 0.025));
-    brakes.insert(3, build_brake(0.5, 0.1, 0.038, 0.016, 0.35, 0.03));
-    brakes.insert(4, build_brake(0.5, 0.104, 0.0375, 0.016, 0.355, 0.03));
-    brakes.insert(5, build_brake(0.4, 0.14, 0.049, 0.0175, 0.35, 0.025));
-    brakes.insert(6, build_brake(0.52, 0.1, 0.0425, 0.02, 0.35, 0.028));
-    brakes.insert(7, build_brake(0.5, 0.11, 0.052, 0.014, 0.35, 0.032));
-    brakes.insert(8, build_brake(0.5, 0.1, 0.0425, 0.02, 0.355, 0.032));
-    brakes.insert(9, build_brake(0.5, 0.132, 0.042, 0.018, 0.355, 0.028));
-    brakes.insert(10, build_brake(0.52, 0.14, 0.042, 0.0175, 0.35, 0.028));
-    brakes.insert(11, build_brake(0.63, 0.1, 0.0425, 0.02, 0.32, 0.028));
-    brakes.insert(12, build_brake(0.52, 0.14, 0.049, 0.0175, 0.33, 0.025));
-    brakes.insert(13, build_brake(0.63, 0.14, 0.042, 0.0175, 0.35, 0.025));
-    brakes.insert(14, build_brake(0.5, 0.132, 0.052, 0.017, 0.355, 0.028));
-    brakes.insert(15, build_brake(0.5, 0.132, 0.049, 0.016, 0.32, 0.032));
-    brakes.insert(16, build_brake(0.4, 0.14, 0.0615, 0.0175, 0.35, 0.028));
-    brakes.insert(17, build_brake(0.5, 0.14, 0.049, 0.0175, 0.35, 0.03));
-    brakes.insert(18, build_brake(0.5, 0.14, 0.0615, 0.0175, 0.32, 0.028));
-    brakes.insert(19, build_brake(0.5, 0.164, 0.049, 0.018, 0.355, 0.03));
-    brakes.insert(20, build_brake(0.52, 0.132, 0.049, 0.022, 0.35, 0.03));
-    brakes.insert(21, build_brake(0.63, 0.14, 0.049, 0.0175, 0.355, 0.03));
-    brakes.insert(22, build_brake(0.63, 0.14, 0.0615, 0.0175, 0.35, 0.025));
-    brakes.insert(23, build_brake(0.52, 0.14, 0.0615, 0.0175, 0.355, 0.032));
-    brakes.insert(24, build_brake(0.63, 0.132, 0.049, 0.025, 0.35, 0.025));
-    brakes.insert(25, build_brake(0.5, 0.152, 0.054, 0.025, 0.35, 0.028));
-    brakes.insert(26, build_brake(0.5, 0.164, 0.051, 0.025, 0.355, 0.028));
-    brakes.insert(27, build_brake(0.52, 0.164, 0.0645, 0.025, 0.35, 0.025));
-    brakes.insert(28, build_brake(0.63, 0.164, 0.051, 0.022, 0.33, 0.03));
-    brakes.insert(29, build_brake(0.52, 0.164, 0.051, 0.025, 0.35, 0.032));
-    brakes.insert(30, build_brake(0.52, 0.152, 0.054, 0.028, 0.355, 0.032));
-    brakes.insert(31, build_brake(0.63, 0.152, 0.054, 0.025, 0.32, 0.03));
-    brakes.insert(32, build_brake(0.52, 0.164, 0.0645, 0.029, 0.355, 0.028));
-    brakes.insert(33, build_brake(0.63, 0.164, 0.0645, 0.025, 0.355, 0.032));
-    brakes
-}
+/// The bounds of the number of cells in series in an electric powertrain's battery pack
+pub(crate) const CELLS_IN_SERIES_BOUNDS: [f64; 2] = [24.0, 160.0];
 
-fn build_brake(
-    density: f64,
-    length: f64,
-    height: f64,
-    width: f64,
-    thickness: f64,
-    radius: f64,
-) -> std::collections::HashMap<String, f64> {
-    [
-        ("density".to_owned(), density),
-        ("length".to_owned(), length),
-        ("height".to_owned(), height),
-        ("width".to_owned(), width),
-        ("thickness".to_owned(), thickness),
-        ("radius".to_owned(), radius),
-    ]
-    .iter()
-    .cloned()
-    .collect()
-}
+/// The bounds of the number of cells in parallel in an electric powertrain's battery pack
+pub(crate) const CELLS_IN_PARALLEL_BOUNDS: [f64; 2] = [1.0, 8.0];
 
-pub(crate) fn get_materials(
-) -> std::collections::HashMap<usize, std::collections::HashMap<String, f64>> {
-    let mut materials: std::collections::HashMap<usize, std::collections::HashMap<String, f64>> =
-        std::collections::HashMap::new();
-    materials
-}
+/// The mass of a battery pack relative to its cells, for the container, wiring and battery
+/// management
+pub(crate) const PACK_MASS_FACTOR: f64 = 1.5;
 
-pub(crate) fn get_motors(
-) -> std::collections::HashMap<usize, std::collections::HashMap<String, f64>> {
-    let mut motors: std::collections::HashMap<usize, std::collections::HashMap<String, f64>> =
-        std::collections::HashMap::new();
-    motors
-}
+/// The height of the battery pack's center of gravity, in m
+pub(crate) const PACK_Y_POSITION: f64 = 0.2;
 
-pub(crate) fn get_suspensions(
-) -> std::collections::HashMap<usize, std::collections::HashMap<String, f64>> {
-    let mut suspensions: std::collections::HashMap<usize, std::collections::HashMap<String, f64>> =
-        std::collections::HashMap::new();
-    suspensions
-}
+/// The maximum power that may be drawn from the battery pack under the FSAE rules, in W
+pub(crate) const PACK_POWER_LIMIT: f64 = 80_000.0;
+
+/// The maximum battery pack voltage allowed under the FSAE rules, in V
+pub(crate) const PACK_VOLTAGE_LIMIT: f64 = 600.0;
+
+/// The fraction of the fuel's chemical energy that a combustion engine delivers at the crank
+pub(crate) const ENGINE_EFFICIENCY: f64 = 0.25;
+
+/// The lower heating value of gasoline, in J/kg
+pub(crate) const FUEL_ENERGY_DENSITY: f64 = 43.4e6;
+
+/// The distance between the front and rear axles, in m, the minimum allowed by the FSAE rules
+/// rounded up
+pub(crate) const WHEELBASE: f64 = 1.6;
+
+/// The distance between the left and right tires of an axle, in m
+pub(crate) const TRACK_WIDTH: f64 = 1.2;
+
+/// The distance that the cabin extends ahead of the front axle, in m
+pub(crate) const CABIN_OVERHANG: f64 = 0.3;
+
+/// The longitudinal position of the battery pack's center of gravity behind the front axle, in m
+pub(crate) const PACK_X_POSITION: f64 = 1.0;
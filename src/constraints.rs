@@ -0,0 +1,150 @@
+//! This contains the inequality constraints of the design problem
+
+use crate::ParameterSchema;
+
+/// One inequality constraint g(x) ≤ 0, evaluated for a particular car
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+    /// The constraint written out in terms of the design variables
+    pub name: &'static str,
+    /// The value of g(x), positive when the constraint is violated
+    pub value: f64,
+}
+
+impl Constraint {
+    /// The constraint `value >= bound`, stored as `bound - value <= 0`
+    pub(crate) fn at_least(name: &'static str, value: f64, bound: f64) -> Self {
+        Constraint {
+            name,
+            value: bound - value,
+        }
+    }
+
+    /// The constraint `value <= bound`, stored as `value - bound <= 0`
+    pub(crate) fn at_most(name: &'static str, value: f64, bound: f64) -> Self {
+        Constraint {
+            name,
+            value: value - bound,
+        }
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        self.value <= 0.0
+    }
+}
+
+/// A row of the linear system: (name, [(parameter name, coefficient)], bound)
+type Row = (&'static str, &'static [(&'static str, f64)], f64);
+
+/// The linear constraints of the problem as an explicit system A·x <= b over the parameter vector
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearConstraints {
+    /// The constraint each row represents
+    pub names: Vec<&'static str>,
+    /// One row per constraint, one column per entry of the parameter vector
+    pub a: Vec<Vec<f64>>,
+    pub b: Vec<f64>,
+}
+
+impl LinearConstraints {
+    /// The linear constraints over a parameter vector laid out by the given schema
+    pub fn new(schema: &ParameterSchema) -> Self {
+        let rows: [Row; 13] = [
+            ("rear_wing_width >= 0.3", &[("rear_wing_width", -1.0)], -0.3),
+            (
+                "rear_wing_y_position >= 0.5 + rear_wing_height / 2",
+                &[("rear_wing_y_position", -1.0), ("rear_wing_height", 0.5)],
+                -0.5,
+            ),
+            (
+                "rear_wing_y_position <= 1.2 - rear_wing_height / 2",
+                &[("rear_wing_y_position", 1.0), ("rear_wing_height", 0.5)],
+                1.2,
+            ),
+            (
+                "front_wing_y_position >= 0.03 + front_wing_height / 2",
+                &[("front_wing_y_position", -1.0), ("front_wing_height", 0.5)],
+                -0.03,
+            ),
+            (
+                "front_wing_y_position <= 0.25 - front_wing_height / 2",
+                &[("front_wing_y_position", 1.0), ("front_wing_height", 0.5)],
+                0.25,
+            ),
+            (
+                "side_wing_y_position >= 0.03 + side_wings_height / 2",
+                &[("side_wing_y_position", -1.0), ("side_wings_height", 0.5)],
+                -0.03,
+            ),
+            (
+                "side_wing_y_position <= 0.25 - side_wings_height / 2",
+                &[("side_wing_y_position", 1.0), ("side_wings_height", 0.5)],
+                0.25,
+            ),
+            (
+                "cabin_y_position >= 0.03 + cabin_height / 2",
+                &[("cabin_y_position", -1.0), ("cabin_height", 0.5)],
+                -0.03,
+            ),
+            (
+                "cabin_y_position <= 1.2 - cabin_height / 2",
+                &[("cabin_y_position", 1.0), ("cabin_height", 0.5)],
+                1.2,
+            ),
+            (
+                "impact_attenuator_length >= 0.2",
+                &[("impact_attenuator_length", -1.0)],
+                -0.2,
+            ),
+            (
+                "impact_attenuator_length <= 0.7 - front_wing_length",
+                &[
+                    ("impact_attenuator_length", 1.0),
+                    ("front_wing_length", 1.0),
+                ],
+                0.7,
+            ),
+            (
+                "impact_attenuator_y_position >= 0.03 + impact_attenuator_height / 2",
+                &[
+                    ("impact_attenuator_y_position", -1.0),
+                    ("impact_attenuator_height", 0.5),
+                ],
+                -0.03,
+            ),
+            (
+                "impact_attenuator_y_position <= 1.2 - impact_attenuator_height / 2",
+                &[
+                    ("impact_attenuator_y_position", 1.0),
+                    ("impact_attenuator_height", 0.5),
+                ],
+                1.2,
+            ),
+        ];
+
+        let mut constraints = LinearConstraints {
+            names: vec![],
+            a: vec![],
+            b: vec![],
+        };
+        for (name, coefficients, bound) in rows.iter() {
+            let mut row = vec![0.0; schema.len()];
+            for &(parameter, coefficient) in coefficients.iter() {
+                row[schema.index_of(parameter).unwrap()] = coefficient;
+            }
+            constraints.names.push(name);
+            constraints.a.push(row);
+            constraints.b.push(*bound);
+        }
+        constraints
+    }
+
+    /// The values A·x - b, each of which should be at most zero
+    pub fn evaluate(&self, x: &[f64]) -> Vec<f64> {
+        self.a
+            .iter()
+            .zip(&self.b)
+            .map(|(row, b)| row.iter().zip(x).map(|(a, x)| a * x).sum::<f64>() - b)
+            .collect()
+    }
+}
@@ -0,0 +1,196 @@
+//! This contains the battery pack and power limits of the electric powertrain
+
+use uom::si::{
+    electric_potential::volt,
+    energy::kilowatt_hour,
+    f64::{ElectricPotential, Energy, Mass, Power},
+    mass::kilogram,
+    power::{kilowatt, watt},
+};
+
+use crate::{constants, BatteryPack, Car, Constraint, Inverter, Sense};
+
+/// The objectives specific to an electric powertrain
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ElectricObjectives {
+    /// The mass of the battery pack, including its container and wiring
+    pub pack_mass: Mass,
+    /// The energy drawn from the battery pack over the endurance event
+    pub endurance_energy: Energy,
+    /// The peak power that the battery pack, inverter and motor can deliver together
+    pub peak_power: Power,
+}
+
+impl ElectricObjectives {
+    /// The number of objectives
+    pub const COUNT: usize = 3;
+
+    /// The name of each objective, in vector order
+    pub const NAMES: [&'static str; 3] = ["pack_mass", "endurance_energy", "peak_power"];
+
+    /// The unit of each entry of [`ElectricObjectives::to_vector`]
+    pub const UNITS: [&'static str; 3] = ["kg", "kWh", "kW"];
+
+    /// The optimization direction of each objective, in vector order
+    pub const SENSES: [Sense; 3] = [Sense::Minimize, Sense::Minimize, Sense::Maximize];
+
+    /// The objectives as plain numbers in the units of [`ElectricObjectives::UNITS`]
+    pub fn to_vector(&self) -> [f64; 3] {
+        [
+            self.pack_mass.get::<kilogram>(),
+            self.endurance_energy.get::<kilowatt_hour>(),
+            self.peak_power.get::<kilowatt>(),
+        ]
+    }
+}
+
+impl Car {
+    /// The battery pack, or `None` for a combustion powertrain
+    pub fn battery_pack(&self) -> Option<&BatteryPack> {
+        self.pack.as_ref()
+    }
+
+    /// The motor controller, or `None` for a combustion powertrain
+    pub fn inverter(&self) -> Option<&Inverter> {
+        self.inverter.as_ref()
+    }
+
+    /// The mass of the battery pack, including its container and wiring, or zero for a
+    /// combustion powertrain
+    pub fn pack_mass(&self) -> Mass {
+        self.pack
+            .as_ref()
+            .map(|pack| pack.cell_mass() * constants::PACK_MASS_FACTOR)
+            .unwrap_or_default()
+    }
+
+    /// The peak power that the powertrain can deliver to the motor shaft
+    ///
+    /// For an electric powertrain this is the lowest of the motor's peak power, the power the
+    /// pack can discharge, the power the inverter can pass at the pack voltage and the 80 kW
+    /// allowed by the rules.
+    pub fn peak_power(&self) -> Power {
+        match self.power_limit() {
+            Some(limit) if limit < self.motor.power => limit,
+            _ => self.motor.power,
+        }
+    }
+
+    /// The objectives of the electric powertrain, or `None` for a combustion powertrain
+    pub fn electric_objectives(&self) -> Option<ElectricObjectives> {
+        self.pack.as_ref()?;
+        Some(ElectricObjectives {
+            pack_mass: self.pack_mass(),
+            endurance_energy: self.endurance_energy(),
+            peak_power: self.peak_power(),
+        })
+    }
+
+    /// The limit that the battery pack and inverter put on the power drawn, if electric
+    pub(crate) fn power_limit(&self) -> Option<Power> {
+        let (pack, inverter) = (self.pack.as_ref()?, self.inverter.as_ref()?);
+        let inverter_power: Power = pack.voltage() * inverter.current;
+        let limit = pack
+            .power()
+            .get::<watt>()
+            .min(inverter_power.get::<watt>())
+            .min(constants::PACK_POWER_LIMIT);
+        Some(Power::new::<watt>(limit))
+    }
+
+    /// The mass of the battery pack and inverter, zero for a combustion powertrain
+    pub(crate) fn mass_electrics(&self) -> Mass {
+        self.pack_mass() + self.mass_inverter()
+    }
+
+    /// The mass of the inverter, mounted with the motor
+    pub(crate) fn mass_inverter(&self) -> Mass {
+        self.inverter
+            .as_ref()
+            .map(|inverter| inverter.mass)
+            .unwrap_or_default()
+    }
+
+    /// The rows of [`Car::nonlinear_constraints`] on the pack voltage, empty for a combustion
+    /// powertrain
+    pub(crate) fn electric_constraints(&self) -> Vec<Constraint> {
+        match (&self.pack, &self.inverter) {
+            (Some(pack), Some(inverter)) => vec![
+                Constraint::at_most(
+                    "pack_voltage <= inverter_voltage",
+                    pack.voltage().get::<volt>(),
+                    inverter.voltage.get::<volt>(),
+                ),
+                Constraint::at_most(
+                    "pack_voltage <= 600 V",
+                    pack.voltage().get::<volt>(),
+                    constants::PACK_VOLTAGE_LIMIT,
+                ),
+            ],
+            _ => vec![],
+        }
+    }
+}
+
+/// The largest number of cells in series whose nominal voltage is within the limits of the
+/// inverter and the rules
+pub(crate) fn max_cells_in_series(cell: ElectricPotential, inverter: ElectricPotential) -> f64 {
+    let voltage = inverter.get::<volt>().min(constants::PACK_VOLTAGE_LIMIT);
+    (voltage / cell.get::<volt>()).floor().clamp(
+        constants::CELLS_IN_SERIES_BOUNDS[0],
+        constants::CELLS_IN_SERIES_BOUNDS[1],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Catalog, Powertrain, ProblemVariant};
+    use rand::{rngs::StdRng, SeedableRng};
+    use uom::si::energy::joule;
+
+    #[test]
+    fn electric() {
+        let variant = ProblemVariant {
+            powertrain: Powertrain::Electric,
+            ..ProblemVariant::ORIGINAL
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let car = Car::new_with_variant_and_rng(Catalog::builtin(), variant, &mut rng);
+        assert!(Car::new_with_rng(&mut rng).electric_objectives().is_none());
+        assert!(car
+            .nonlinear_constraints()
+            .iter()
+            .all(|constraint| constraint.value <= 0.0));
+
+        let p = car.get_parameter_vector();
+        assert_eq!(p.len(), variant.parameter_count());
+        let rebuilt = Car::new_from_parameters_with_variant(&p, Catalog::builtin(), variant);
+        assert_eq!(rebuilt.battery_pack(), car.battery_pack());
+        assert_eq!(rebuilt.inverter(), car.inverter());
+
+        // Cell counts out of range are rounded and clamped like catalog indices
+        let schema = car.parameter_schema();
+        let mut p = p;
+        p[schema.index_of("cells_in_series").unwrap()] = -3.0;
+        p[schema.index_of("cells_in_parallel").unwrap()] = 11.6;
+        let clamped = Car::new_from_parameters_with_variant(&p, Catalog::builtin(), variant);
+        let pack = clamped.battery_pack().unwrap();
+        assert_eq!(pack.series, constants::CELLS_IN_SERIES_BOUNDS[0] as usize);
+        assert_eq!(
+            pack.parallel,
+            constants::CELLS_IN_PARALLEL_BOUNDS[1] as usize
+        );
+        let round_trip = clamped.get_parameter_vector();
+        let rebuilt =
+            Car::new_from_parameters_with_variant(&round_trip, Catalog::builtin(), variant);
+        assert_eq!(rebuilt.battery_pack(), clamped.battery_pack());
+
+        let objectives = car.electric_objectives().unwrap();
+        let pack = car.battery_pack().unwrap();
+        assert!(objectives.pack_mass > pack.cell_mass());
+        assert!(objectives.peak_power.get::<watt>() <= constants::PACK_POWER_LIMIT);
+        assert!(objectives.endurance_energy > Energy::new::<joule>(0.0));
+        assert!(car.mass() > objectives.pack_mass);
+    }
+}
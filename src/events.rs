@@ -0,0 +1,242 @@
+//! This contains the Formula SAE dynamic events and their scoring
+
+use uom::si::{
+    energy::joule,
+    f64::{Energy, Length, Mass, Time},
+    length::meter,
+    mass::kilogram,
+    time::second,
+};
+
+use std::sync::{Arc, OnceLock};
+
+use crate::{constants, Car, Track};
+
+/// The time taken in each dynamic event
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EventTimes {
+    /// A 75 m run from standstill
+    pub acceleration: Time,
+    /// One lap of the skidpad circle, the average of the timed left and right laps
+    pub skidpad: Time,
+    pub autocross: Time,
+    pub endurance: Time,
+}
+
+/// The points scored in each dynamic event
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EventScores {
+    pub acceleration: f64,
+    pub skidpad: f64,
+    pub autocross: f64,
+    pub endurance: f64,
+}
+
+impl EventScores {
+    /// The points scored over all four events
+    pub fn total(&self) -> f64 {
+        self.acceleration + self.skidpad + self.autocross + self.endurance
+    }
+}
+
+/// The layouts of the dynamic events and the best times that scores are measured against
+#[derive(Clone, Debug, PartialEq)]
+pub struct Competition {
+    /// The fastest time in each event, which earns full points
+    pub best_times: EventTimes,
+    pub autocross: Track,
+    pub endurance_lap: Track,
+    pub endurance_laps: usize,
+}
+
+impl Default for Competition {
+    /// The bundled autocross and endurance layouts, 22 laps of endurance, and best times
+    /// typical of a competitive car
+    fn default() -> Self {
+        Competition::clone(&Competition::shared())
+    }
+}
+
+impl Competition {
+    /// The default competition, built on first use and shared by every car scored against it
+    pub(crate) fn shared() -> Arc<Competition> {
+        static DEFAULT: OnceLock<Arc<Competition>> = OnceLock::new();
+        DEFAULT
+            .get_or_init(|| Arc::new(Competition::bundled()))
+            .clone()
+    }
+
+    fn bundled() -> Self {
+        Competition {
+            best_times: EventTimes {
+                acceleration: Time::new::<second>(4.0),
+                skidpad: Time::new::<second>(5.0),
+                autocross: Time::new::<second>(38.0),
+                endurance: Time::new::<second>(1470.0),
+            },
+            autocross: Track::autocross(),
+            endurance_lap: Track::endurance_lap(),
+            endurance_laps: 22,
+        }
+    }
+
+    /// Drive each of the dynamic events
+    pub fn times(&self, car: &Car) -> EventTimes {
+        let acceleration = car
+            .simulate_run(
+                &Track::straight(Length::new::<meter>(75.0)),
+                Default::default(),
+            )
+            .time;
+
+        let radius = car.conditions.skidpad_radius;
+        let skidpad_speed = car.cornering_limit(radius);
+        let skidpad =
+            Time::new::<second>(2.0 * std::f64::consts::PI * radius.get::<meter>() / skidpad_speed);
+
+        let autocross = car.simulate_run(&self.autocross, Default::default()).time;
+
+        EventTimes {
+            acceleration,
+            skidpad,
+            autocross,
+            endurance: self.endurance(car).0,
+        }
+    }
+
+    /// The energy drawn from the engine or battery over the endurance event, infinite if the car
+    /// cannot finish it
+    pub fn endurance_energy(&self, car: &Car) -> Energy {
+        self.endurance(car).1
+    }
+
+    /// The time and energy of the endurance event, a lap from standstill followed by flying laps
+    /// that each start at the speed the first one finishes with
+    fn endurance(&self, car: &Car) -> (Time, Energy) {
+        let first_lap = car.simulate_run(&self.endurance_lap, Default::default());
+        let start = first_lap
+            .trace
+            .last()
+            .map(|point| point.speed)
+            .unwrap_or_default();
+        let flying_lap = car.simulate_run(&self.endurance_lap, start);
+        let laps = self.endurance_laps.saturating_sub(1) as f64;
+        let time = first_lap.time + flying_lap.time * laps;
+        let energy = if time.value.is_finite() {
+            first_lap.energy + flying_lap.energy * laps
+        } else {
+            Energy::new::<joule>(f64::INFINITY)
+        };
+        (time, energy)
+    }
+
+    /// Convert event times into points with the competition scoring formulas
+    pub fn score_times(&self, times: &EventTimes) -> EventScores {
+        let best = &self.best_times;
+        EventScores {
+            acceleration: score(times.acceleration, best.acceleration, 1.5, 1.0, 95.5, 4.5),
+            skidpad: score(times.skidpad, best.skidpad, 1.25, 2.0, 71.5, 3.5),
+            autocross: score(times.autocross, best.autocross, 1.45, 1.0, 118.5, 6.5),
+            endurance: score(times.endurance, best.endurance, 1.45, 1.0, 250.0, 25.0),
+        }
+    }
+
+    /// Drive and score each of the dynamic events
+    pub fn score(&self, car: &Car) -> EventScores {
+        self.score_times(&self.times(car))
+    }
+}
+
+/// Points for an event scored as `points * ((t_max / t)^exponent - 1) / (factor^exponent - 1) +
+/// base`, where `t_max = factor * t_min`, clamped to between `base` and `points + base`
+fn score(time: Time, best: Time, factor: f64, exponent: f64, points: f64, base: f64) -> f64 {
+    let time = time.get::<second>().max(best.get::<second>());
+    if !time.is_finite() {
+        return 0.0;
+    }
+    let ratio = factor * best.get::<second>() / time;
+    let performance = (ratio.powf(exponent) - 1.0) / (factor.powf(exponent) - 1.0);
+    points * performance.max(0.0) + base
+}
+
+impl Car {
+    /// The points scored in the dynamic events of this car's [`Competition`], or of the default
+    /// one if none was given
+    pub fn dynamic_event_scores(&self) -> EventScores {
+        match &self.competition {
+            Some(competition) => competition.score(self),
+            None => Competition::shared().score(self),
+        }
+    }
+
+    /// The energy drawn over the endurance event of this car's [`Competition`], or of the default
+    /// one if none was given, infinite if the car cannot finish it
+    pub fn endurance_energy(&self) -> Energy {
+        match &self.competition {
+            Some(competition) => competition.endurance_energy(self),
+            None => Competition::shared().endurance_energy(self),
+        }
+    }
+
+    /// The energy consumed over the endurance event: the fuel's chemical energy for a combustion
+    /// engine or the battery energy for an electric powertrain
+    pub fn endurance_consumption(&self) -> Energy {
+        match self.battery_pack() {
+            Some(_) => self.endurance_energy(),
+            None => self.endurance_energy() / constants::ENGINE_EFFICIENCY,
+        }
+    }
+
+    /// The mass of gasoline burned over the endurance event, or `None` for an electric
+    /// powertrain
+    pub fn endurance_fuel(&self) -> Option<Mass> {
+        match self.battery_pack() {
+            Some(_) => None,
+            None => Some(Mass::new::<kilogram>(
+                self.endurance_consumption().get::<joule>() / constants::FUEL_ENERGY_DENSITY,
+            )),
+        }
+    }
+
+    /// The negated total points of [`Car::dynamic_event_scores`], an alternative to
+    /// [`Car::objective`] that is also to be minimized
+    pub fn dynamic_event_objective(&self) -> f64 {
+        -self.dynamic_event_scores().total()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn scoring() {
+        let competition = Competition::default();
+        let best = competition.score_times(&competition.best_times);
+        assert!((best.total() - (100.0 + 75.0 + 125.0 + 275.0)).abs() < 1e-9);
+
+        let slow = EventTimes {
+            acceleration: Time::new::<second>(6.0),
+            skidpad: Time::new::<second>(6.25),
+            autocross: Time::new::<second>(38.0 * 1.45),
+            endurance: Time::new::<second>(f64::INFINITY),
+        };
+        let scores = competition.score_times(&slow);
+        assert!((scores.acceleration - 4.5).abs() < 1e-9);
+        assert!((scores.skidpad - 3.5).abs() < 1e-9);
+        assert!((scores.autocross - 6.5).abs() < 1e-9);
+        assert_eq!(scores.endurance, 0.0);
+    }
+
+    #[test]
+    fn events() {
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let times = Competition::default().times(&car);
+        assert!(times.acceleration.value > 0.0);
+        assert!(times.endurance >= times.autocross);
+        let total = car.dynamic_event_scores().total();
+        assert!((0.0..=575.0).contains(&total));
+        assert_eq!(car.dynamic_event_objective(), -total);
+    }
+}
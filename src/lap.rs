@@ -0,0 +1,236 @@
+//! This contains a point-mass lap time simulation built on the car model
+
+use uom::si::{
+    energy::joule,
+    f64::{Energy, Length, Time, Velocity},
+    length::meter,
+    time::second,
+    velocity::meter_per_second,
+};
+
+use crate::{Car, Track};
+
+/// The distance between the points at which the velocity profile is computed
+const STEP: f64 = 0.5;
+
+/// The lowest speed at which the powertrain is evaluated, since it is undefined at standstill
+const MIN_SPEED: f64 = 0.1;
+
+/// The speed of the car at one point along the track
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LapPoint {
+    pub distance: Length,
+    pub speed: Velocity,
+}
+
+/// The outcome of driving a track
+#[derive(Clone, Debug, PartialEq)]
+pub struct LapResult {
+    /// The time taken, infinite if the car cannot complete the track
+    pub time: Time,
+    /// The speed at evenly spaced points along the track, starting at distance zero
+    pub trace: Vec<LapPoint>,
+    /// The energy drawn from the engine or battery, without recovery under braking
+    pub energy: Energy,
+}
+
+impl Car {
+    /// Drive one flying lap of a closed track
+    ///
+    /// The lap starts at the speed with which the car finishes a lap from standstill.
+    pub fn simulate_lap(&self, track: &Track) -> LapResult {
+        let standing = self.simulate_run(track, Velocity::default());
+        let start = standing
+            .trace
+            .last()
+            .map(|point| point.speed)
+            .unwrap_or_default();
+        self.simulate_run(track, start)
+    }
+
+    /// Drive the track once, starting at the given speed
+    ///
+    /// The velocity profile is the lowest of the cornering limit, a forward pass accelerating
+    /// out of every corner and a backward pass braking into every corner. Climbs slow the car
+    /// and help it brake, while descents do the opposite. Segments of zero length are skipped, and
+    /// a track with no length is finished where it starts.
+    pub fn simulate_run(&self, track: &Track, start: Velocity) -> LapResult {
+        // Split the track into steps, with the cornering limit at each point and the slope of the
+        // step leading up to it. A point where two segments meet takes the lower of their limits.
+        let mut distances = vec![0.0];
+        let mut limits: Vec<f64> = vec![];
+        let mut grades = vec![];
+        for segment in &track.segments {
+            let length = segment.length().get::<meter>();
+            if length <= 0.0 || length.is_nan() {
+                continue;
+            }
+            let steps = (length / STEP).ceil().max(1.0) as usize;
+            let limit = match segment.radius() {
+                Some(radius) => self.cornering_limit(radius),
+                None => f64::INFINITY,
+            };
+            match limits.last_mut() {
+                Some(last) => *last = last.min(limit),
+                None => {
+                    limits.push(limit);
+                    grades.push(0.0);
+                }
+            }
+            let offset = *distances.last().unwrap();
+            for i in 1..=steps {
+                distances.push(offset + length * i as f64 / steps as f64);
+                limits.push(limit);
+                grades.push(segment.grade());
+            }
+        }
+        if limits.is_empty() {
+            return LapResult {
+                time: Time::default(),
+                trace: vec![LapPoint {
+                    distance: Length::default(),
+                    speed: start,
+                }],
+                energy: Energy::default(),
+            };
+        }
+        let g = self.conditions.gravity.value;
+
+        // A single copy of the car whose operating speed follows it along the track
+        let mut car = self.clone();
+
+        // Forward pass, accelerating wherever the cornering limit allows
+        let mut speeds = vec![start.get::<meter_per_second>().min(limits[0])];
+        for i in 1..distances.len() {
+            let ds = distances[i] - distances[i - 1];
+            let v = speeds[i - 1];
+            car.conditions.vehicle_speed = Velocity::new::<meter_per_second>(v.max(MIN_SPEED));
+            let a = car.acceleration().value - g * grades[i];
+            let next = (v * v + 2.0 * a * ds).max(0.0).sqrt();
+            speeds.push(next.min(limits[i]));
+        }
+
+        // Backward pass, braking early enough to make every corner
+        for i in (0..distances.len() - 1).rev() {
+            let ds = distances[i + 1] - distances[i];
+            let v = speeds[i + 1];
+            car.conditions.vehicle_speed = Velocity::new::<meter_per_second>(v.max(MIN_SPEED));
+            let d = car.braking_deceleration().value + g * grades[i + 1];
+            speeds[i] = speeds[i].min((v * v + 2.0 * d * ds).max(0.0).sqrt());
+        }
+
+        let time = distances
+            .windows(2)
+            .zip(speeds.windows(2))
+            .map(|(d, v)| 2.0 * (d[1] - d[0]) / (v[0] + v[1]))
+            .sum::<f64>();
+
+        // Work done at the wheels wherever the powertrain pushes the car along
+        let mass = self.mass().value;
+        let work = (1..distances.len())
+            .map(|i| {
+                let ds = distances[i] - distances[i - 1];
+                let (v0, v1) = (speeds[i - 1], speeds[i]);
+                car.conditions.vehicle_speed = Velocity::new::<meter_per_second>(0.5 * (v0 + v1));
+                let resistance = car.total_resistance().value;
+                let force =
+                    mass * (v1 * v1 - v0 * v0) / (2.0 * ds) + resistance + mass * g * grades[i];
+                force.max(0.0) * ds
+            })
+            .sum::<f64>();
+
+        LapResult {
+            time: Time::new::<second>(time),
+            energy: Energy::new::<joule>(work / self.powertrain_efficiency()),
+            trace: distances
+                .into_iter()
+                .zip(speeds)
+                .map(|(distance, speed)| LapPoint {
+                    distance: Length::new::<meter>(distance),
+                    speed: Velocity::new::<meter_per_second>(speed),
+                })
+                .collect(),
+        }
+    }
+
+    /// The highest speed in m/s at which the car can hold a corner of the given radius, with
+    /// downforce growing with the square of speed
+    pub(crate) fn cornering_limit(&self, radius: Length) -> f64 {
+        let grip = self.lateral_grip();
+        let v0 = self.conditions.vehicle_speed.get::<meter_per_second>();
+        let downforce_per_speed_squared = self.total_downward_force().value / (v0 * v0);
+        let mass = self.mass().value;
+        let radius = radius.get::<meter>();
+        let denominator = 1.0 - grip * downforce_per_speed_squared * radius / mass;
+        if denominator <= 0.0 {
+            f64::INFINITY
+        } else {
+            (grip * self.conditions.gravity.value * radius / denominator).sqrt()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, Segment};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn lap() {
+        // This design can pull away from standstill and climb the hill below
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(2));
+        let track = Track::new(vec![
+            Segment::Straight {
+                length: Length::new::<meter>(100.0),
+                elevation_change: Length::default(),
+            },
+            Segment::Corner {
+                radius: Length::new::<meter>(10.0),
+                length: Length::new::<meter>(31.4),
+                direction: Direction::Left,
+                elevation_change: Length::default(),
+            },
+        ]);
+        let result = car.simulate_lap(&track);
+        let last = result.trace.last().unwrap();
+        assert_eq!(result.trace[0].distance, Length::default());
+        assert!((last.distance - track.length()).value.abs() < 1e-9);
+
+        let limit = car.cornering_limit(Length::new::<meter>(10.0));
+        for point in &result.trace {
+            assert!(point.speed.value <= limit || point.distance.value < 100.0);
+        }
+        let fastest = result
+            .trace
+            .iter()
+            .map(|point| point.speed.value)
+            .fold(0.0, f64::max);
+        assert!(result.time.value >= track.length().value / fastest);
+
+        let hill = |rise: f64| {
+            Track::new(vec![Segment::Straight {
+                length: Length::new::<meter>(100.0),
+                elevation_change: Length::new::<meter>(rise),
+            }])
+        };
+        let flat = car.simulate_run(&hill(0.0), Velocity::default()).time;
+        assert!(flat.value.is_finite());
+        assert!(car.simulate_run(&hill(2.0), Velocity::default()).time > flat);
+        assert!(car.simulate_run(&hill(-2.0), Velocity::default()).time < flat);
+
+        // Empty tracks and segments of no length take no time
+        let empty = car.simulate_lap(&Track::new(vec![]));
+        assert_eq!(empty.time, Time::default());
+        assert_eq!(empty.trace.len(), 1);
+        let mut padded = hill(0.0);
+        padded.segments.insert(
+            0,
+            Segment::Straight {
+                length: Length::default(),
+                elevation_change: Length::new::<meter>(1.0),
+            },
+        );
+        assert_eq!(car.simulate_run(&padded, Velocity::default()).time, flat);
+    }
+}
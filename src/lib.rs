@@ -1,36 +1,75 @@
+mod bounds;
+mod catalog;
+mod components;
+mod conditions;
 mod constants;
+mod constraints;
+mod electric;
+mod events;
+mod lap;
+mod loads;
+mod normalization;
+mod objectives;
+mod ride;
+mod schema;
+mod sweep;
+mod table;
+mod track;
 mod utils;
+mod variant;
+mod weights;
+
+pub use bounds::{BoundViolation, BoundsReport};
+pub use catalog::{Catalog, CatalogError};
+pub use components::{
+    BatteryPack, Brake, Cell, DampingCoefficient, Inverter, Material, Motor, SpringRate,
+    Suspension, Tire, TireGrip, TorqueCurve,
+};
+pub use conditions::OperatingConditions;
+pub use constraints::{Constraint, LinearConstraints};
+pub use electric::ElectricObjectives;
+pub use events::{Competition, EventScores, EventTimes};
+pub use lap::{LapPoint, LapResult};
+pub use loads::AxleLoads;
+pub use normalization::Normalization;
+pub use objectives::{Objectives, Sense};
+pub use ride::{Axle, RandomRoad, RideResult, RoadClass, RoadProfile};
+pub use schema::{Parameter, ParameterKind, ParameterSchema};
+pub use sweep::{SpeedPoint, SpeedSweep};
+pub use track::{Direction, Segment, Track, TrackError};
+pub use variant::{Powertrain, ProblemVariant};
+pub use weights::WeightScenario;
+
+use std::sync::Arc;
+
+use rand::{thread_rng, Rng};
 
 use uom::si::{
-    acceleration::meter_per_second_squared,
     angle::radian,
-    f64::{Acceleration, Angle, Length, Mass, MassDensity, Power, Pressure, Ratio, Torque},
+    angular_velocity::radian_per_second,
+    energy::{joule, kilowatt_hour},
+    f64::{
+        Acceleration, Angle, AngularVelocity, Force, Length, Mass, Pressure, Torque, Velocity,
+        Volume,
+    },
+    force::newton,
     length::meter,
     mass::kilogram,
-    mass_density::kilogram_per_cubic_meter,
-    pressure::pascal,
+    pressure::bar,
     ratio::ratio,
+    torque::newton_meter,
+    velocity::{kilometer_per_hour, meter_per_second},
 };
-use uom::typenum::Len;
-
-const V_CAR: f64 = 26.8;
-const W_E: f64 = 3600.0 * 2.0 * std::f64::consts::PI / 60.0;
-const RHO_AIR: MassDensity = MassDensity::new::<kilogram_per_cubic_meter>(1.225);
-const R_TRACK: f64 = Length::new::<meters>(9.0);
-const P_BRAKE: f64 = 10_000_000.0;
-const C_DC: Ratio = Ratio::new::<ratio>(0.04);
-const GRAVITY: Acceleration = Acceleration::new::<meter_per_second_squared>(9.81);
-const Y_SUSPENSION: f64 = 0.05;
-const DYDT_SUSPENSION: f64 = 0.025;
-
-// Weights
-const EVEN_WEIGHTS: [f64; 11] = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
-const WEIGHTS1: [i32; 11] = [14, 1, 20, 30, 10, 1, 1, 10, 10, 2, 1]; // TODO: Make f64
-const WEIGHTS2: [i32; 11] = [25, 1, 15, 20, 15, 1, 1, 15, 5, 1, 1]; // TODO: Make f64
-const WEIGHTS3: [i32; 11] = [14, 1, 20, 15, 25, 1, 1, 10, 10, 2, 1]; // TODO: Make f64
-
-#[derive(Default)]
+
+#[derive(Clone, Debug, Default)]
 pub struct Car {
+    catalog: Arc<Catalog>,
+    variant: ProblemVariant,
+    weights: WeightScenario,
+    normalization: Option<Normalization>,
+    conditions: OperatingConditions,
+    competition: Option<Arc<Competition>>,
+    energy_weight: Option<f64>,
     rear_wing_material_index: usize,
     front_wing_material_index: usize,
     side_wing_material_index: usize,
@@ -40,7 +79,12 @@ pub struct Car {
     front_tire_index: usize,
     brake_index: usize,
     motor_index: usize,
-    suspension_index: usize,
+    rear_suspension_index: usize,
+    front_suspension_index: usize,
+    /// Engine revolutions per revolution of the rear wheels
+    final_drive_ratio: f64,
+    cell_index: usize,
+    inverter_index: usize,
     rear_wing_height: Length,
     rear_wing_length: Length,
     rear_wing_angle_of_attack: Angle,
@@ -60,33 +104,19 @@ pub struct Car {
     cabin_thickness: Length,
     impact_attenuator_height: Length,
     impact_attenuator_width: Length,
-    rear_wing_density: MassDensity,
-    front_wing_density: MassDensity,
-    side_wing_density: MassDensity,
-    cabin_density: MassDensity,
-    impact_attenuator_density: MassDensity,
-    impact_attenuator_modulus: Pressure,
-    rear_tire_radius: Length,
-    rear_tire_mass: Mass,
-    front_tire_radius: Length,
-    front_tire_mass: Mass,
-    engine_power: Power,
-    engine_length: Length,
-    engine_height: Length,
-    engine_torque: Torque,
-    engine_mass: Mass,
-    brake_radius: Length,
-    brake_density: MassDensity,
-    brake_length: Length,
-    brake_height: Length,
-    brake_width: Length,
-    brake_thickness: Length,
-    rear_suspension_spring_constant: f64,
-    rear_suspension_damping_coefficient: f64,
-    rear_suspension_mass: Mass,
-    front_suspension_spring_constant: f64,
-    front_suspension_damping_coefficient: f64,
-    front_suspension_mass: Mass,
+    rear_wing_material: Material,
+    front_wing_material: Material,
+    side_wing_material: Material,
+    cabin_material: Material,
+    impact_attenuator_material: Material,
+    rear_tire: Tire,
+    front_tire: Tire,
+    brake: Brake,
+    motor: Motor,
+    rear_suspension: Suspension,
+    front_suspension: Suspension,
+    pack: Option<BatteryPack>,
+    inverter: Option<Inverter>,
     rear_wing_width: Length,
     rear_wing_y_position: Length,
     front_wing_y_position: Length,
@@ -101,52 +131,146 @@ pub struct Car {
 
 impl Car {
     pub fn new() -> Self {
+        Car::new_with_catalog(Catalog::shared())
+    }
+
+    /// Generate a random car using the given random number generator, so that a seeded
+    /// generator always produces the same design
+    pub fn new_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Car::new_with_variant_and_rng(Catalog::shared(), ProblemVariant::ORIGINAL, rng)
+    }
+
+    /// Generate a random car whose components are drawn from the given catalog
+    pub fn new_with_catalog(catalog: impl Into<Arc<Catalog>>) -> Self {
+        Car::new_with_variant(catalog, ProblemVariant::ORIGINAL)
+    }
+
+    /// Generate a random car for the given formulation of the problem
+    pub fn new_with_variant(catalog: impl Into<Arc<Catalog>>, variant: ProblemVariant) -> Self {
+        Car::new_with_variant_and_rng(catalog, variant, &mut thread_rng())
+    }
+
+    /// Generate a random car for the given formulation of the problem using the given random
+    /// number generator
+    pub fn new_with_variant_and_rng<R: Rng + ?Sized>(
+        catalog: impl Into<Arc<Catalog>>,
+        variant: ProblemVariant,
+        rng: &mut R,
+    ) -> Self {
         // Get libraries
-        let tires = constants::get_tires();
-        let brakes = constants::get_brakes();
-        let motors = constants::get_motors();
-        let materials = constants::get_materials();
-        let suspensions = constants::get_suspensions();
+        let catalog = catalog.into();
+        let electric = variant.powertrain == Powertrain::Electric;
+        let tires = catalog.tires();
+        let brakes = catalog.brakes();
+        let motors = if electric {
+            catalog.electric_motors()
+        } else {
+            catalog.motors()
+        };
+        let materials = catalog.materials();
+        let suspensions = catalog.suspensions();
 
         // Define indicates
-        let rear_tire_index = utils::multinomial_draw(vec![1.0; tires.len()]);
-        let front_tire_index = utils::multinomial_draw(vec![1.0; tires.len()]);
-        let brake_index = utils::multinomial_draw(vec![1.0; brakes.len()]);
-        let motor_index = utils::multinomial_draw(vec![1.0; motors.len()]);
-        let suspension_index = utils::multinomial_draw(vec![1.0; suspensions.len()]);
-        let rear_wing_material_index = utils::multinomial_draw(vec![1.0; materials.len()]);
-        let front_wing_material_index = utils::multinomial_draw(vec![1.0; materials.len()]);
-        let side_wing_material_index = utils::multinomial_draw(vec![1.0; materials.len()]);
-        let cabin_material_index = utils::multinomial_draw(vec![1.0; materials.len()]);
-        let impact_attenuator_material_index = utils::multinomial_draw(vec![1.0; materials.len()]);
+        let rear_tire_index = utils::multinomial_draw(rng, vec![1.0; tires.len()]);
+        let front_tire_index = utils::multinomial_draw(rng, vec![1.0; tires.len()]);
+        let brake_index = utils::multinomial_draw(rng, vec![1.0; brakes.len()]);
+        let motor_index = utils::multinomial_draw(rng, vec![1.0; motors.len()]);
+        let rear_suspension_index = utils::multinomial_draw(rng, vec![1.0; suspensions.len()]);
+        let front_suspension_index = if variant.independent_suspension {
+            utils::multinomial_draw(rng, vec![1.0; suspensions.len()])
+        } else {
+            rear_suspension_index
+        };
+        let final_drive_ratio = if variant.variable_final_drive {
+            utils::random_uniform(
+                rng,
+                constants::FINAL_DRIVE_BOUNDS[0],
+                constants::FINAL_DRIVE_BOUNDS[1],
+            )
+        } else {
+            constants::FINAL_DRIVE_RATIO
+        };
+        let (cell_index, inverter_index, pack, inverter) = if electric {
+            let cell_index = utils::multinomial_draw(rng, vec![1.0; catalog.cells().len()]);
+            let inverter_index = utils::multinomial_draw(rng, vec![1.0; catalog.inverters().len()]);
+            let (cell, inverter) = (
+                &catalog.cells()[cell_index],
+                &catalog.inverters()[inverter_index],
+            );
+            let series = rng.gen_range(
+                constants::CELLS_IN_SERIES_BOUNDS[0] as usize
+                    ..=electric::max_cells_in_series(cell.voltage, inverter.voltage) as usize,
+            );
+            let parallel = rng.gen_range(
+                constants::CELLS_IN_PARALLEL_BOUNDS[0] as usize
+                    ..=constants::CELLS_IN_PARALLEL_BOUNDS[1] as usize,
+            );
+            let pack = BatteryPack {
+                cell: cell.clone(),
+                series,
+                parallel,
+            };
+            (
+                cell_index,
+                inverter_index,
+                Some(pack),
+                Some(inverter.clone()),
+            )
+        } else {
+            (0, 0, None, None)
+        };
+        let rear_wing_material_index = utils::multinomial_draw(rng, vec![1.0; materials.len()]);
+        let front_wing_material_index = utils::multinomial_draw(rng, vec![1.0; materials.len()]);
+        let side_wing_material_index = utils::multinomial_draw(rng, vec![1.0; materials.len()]);
+        let cabin_material_index = utils::multinomial_draw(rng, vec![1.0; materials.len()]);
+        let impact_attenuator_material_index =
+            utils::multinomial_draw(rng, vec![1.0; materials.len()]);
 
         // Variables used in multiple places
-        let impact_attenuator_height = Length::new::<meter>(utils::random_uniform(
+        let impact_attenuator_height = utils::random_uniform(
+            rng,
             constants::CONST_BOUNDS[17][0],
             constants::CONST_BOUNDS[17][1],
-        ));
-        let front_wing_length = Length::new::<meter>(utils::random_uniform(
+        );
+        let front_wing_length = utils::random_uniform(
+            rng,
             constants::CONST_BOUNDS[4][0],
             constants::CONST_BOUNDS[4][1],
-        ));
-        let cabin_height = Length::new::<meter>(utils::random_uniform(
+        );
+        let cabin_height = utils::random_uniform(
+            rng,
             constants::CONST_BOUNDS[13][0],
             constants::CONST_BOUNDS[13][1],
-        ));
-        let rear_wing_height = Length::new::<meter>(utils::random_uniform(
+        );
+        let rear_wing_height = utils::random_uniform(
+            rng,
             constants::CONST_BOUNDS[0][0],
             constants::CONST_BOUNDS[0][1],
-        ));
-        let front_wing_height = Length::new::<meter>(utils::random_uniform(
+        );
+        let front_wing_height = utils::random_uniform(
+            rng,
             constants::CONST_BOUNDS[3][0],
             constants::CONST_BOUNDS[3][1],
-        ));
-        let side_wings_height = Length::new::<meter>(utils::random_uniform(
+        );
+        let side_wings_height = utils::random_uniform(
+            rng,
             constants::CONST_BOUNDS[7][0],
             constants::CONST_BOUNDS[7][1],
-        ));
+        );
+
+        // Selected components
+        let rear_tire = tires[rear_tire_index].clone();
+        let front_tire = tires[front_tire_index].clone();
+        let motor = motors[motor_index].clone();
 
         Car {
+            catalog: catalog.clone(),
+            variant,
+            weights: WeightScenario::default(),
+            normalization: None,
+            conditions: OperatingConditions::default(),
+            competition: None,
+            energy_weight: None,
             // Index variables
             rear_wing_material_index,
             front_wing_material_index,
@@ -157,219 +281,306 @@ impl Car {
             front_tire_index,
             brake_index,
             motor_index,
-            suspension_index,
+            rear_suspension_index,
+            front_suspension_index,
+            final_drive_ratio,
+            cell_index,
+            inverter_index,
+            pack,
+            inverter,
             // Parameters with uniform bounds
-            rear_wing_height,
+            rear_wing_height: Length::new::<meter>(rear_wing_height),
             rear_wing_length: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[1][0],
                 constants::CONST_BOUNDS[1][1],
             )),
             rear_wing_angle_of_attack: Angle::new::<radian>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[2][0],
                 constants::CONST_BOUNDS[2][1],
             )),
-            front_wing_height,
-            front_wing_length,
+            front_wing_height: Length::new::<meter>(front_wing_height),
+            front_wing_length: Length::new::<meter>(front_wing_length),
             front_wing_width: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[5][0],
                 constants::CONST_BOUNDS[5][1],
             )),
             front_wing_angle_of_attack: Angle::new::<radian>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[6][0],
                 constants::CONST_BOUNDS[6][1],
             )),
-            side_wings_height,
+            side_wings_height: Length::new::<meter>(side_wings_height),
             side_wings_length: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[8][0],
                 constants::CONST_BOUNDS[8][1],
             )),
             side_wings_width: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[9][0],
                 constants::CONST_BOUNDS[9][1],
             )),
             side_wings_angle_of_attack: Angle::new::<radian>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[10][0],
                 constants::CONST_BOUNDS[10][1],
             )),
-            rear_tire_pressure: Pressure::new::<pascal>(utils::random_uniform(
+            rear_tire_pressure: Pressure::new::<bar>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[11][0],
                 constants::CONST_BOUNDS[11][1],
             )),
-            front_tire_pressure: Pressure::new::<pascal>(utils::random_uniform(
+            front_tire_pressure: Pressure::new::<bar>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[12][0],
                 constants::CONST_BOUNDS[12][1],
             )),
-            cabin_height,
+            cabin_height: Length::new::<meter>(cabin_height),
             cabin_length: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[14][0],
                 constants::CONST_BOUNDS[14][1],
             )),
             cabin_width: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[15][0],
                 constants::CONST_BOUNDS[15][1],
             )),
             cabin_thickness: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[16][0],
                 constants::CONST_BOUNDS[16][1],
             )),
-            impact_attenuator_height,
+            impact_attenuator_height: Length::new::<meter>(impact_attenuator_height),
             impact_attenuator_width: Length::new::<meter>(utils::random_uniform(
+                rng,
                 constants::CONST_BOUNDS[18][0],
                 constants::CONST_BOUNDS[18][1],
             )),
 
-            // Parameters based on lookup from indices
-            rear_wing_density: MassDensity::new::<kilogram_per_cubic_meter>(
-                materials[&rear_wing_material_index]["density"],
-            ),
-            front_wing_density: MassDensity::new::<kilogram_per_cubic_meter>(
-                materials[&front_wing_material_index]["density"],
-            ),
-            side_wing_density: MassDensity::new::<kilogram_per_cubic_meter>(
-                materials[&side_wing_material_index]["density"],
-            ),
-            cabin_density: MassDensity::new::<kilogram_per_cubic_meter>(
-                materials[&cabin_material_index]["density"],
-            ),
-            impact_attenuator_density: MassDensity::new::<kilogram_per_cubic_meter>(
-                materials[&impact_attenuator_material_index]["density"],
-            ),
-            impact_attenuator_modulus: materials[&impact_attenuator_material_index]["modulus"],
-            rear_tire_radius: Length::new::<meter>(tires[&rear_tire_index]["radius"]),
-            rear_tire_mass: Mass::new::<kilogram>(tires[&rear_tire_index]["mass"]),
-            front_tire_radius: Length::new::<meter>(tires[&front_tire_index]["radius"]),
-            front_tire_mass: Mass::new::<kilogram>(tires[&front_tire_index]["mass"]),
-            engine_power: motors[&motor_index]["power"],
-            engine_length: Length::new::<meter>(motors[&motor_index]["length"]),
-            engine_height: Length::new::<meter>(motors[&motor_index]["height"]),
-            engine_torque: motors[&motor_index]["torque"],
-            engine_mass: Mass::new::<kilogram>(motors[&motor_index]["mass"]),
-            brake_radius: Length::new::<meter>(brakes[&brake_index]["radius"]),
-            brake_density: MassDensity::new::<kilogram_per_cubic_meter>(
-                brakes[&brake_index]["density"],
-            ),
-            brake_length: Length::new::<meter>(brakes[&brake_index]["length"]),
-            brake_height: Length::new::<meter>(brakes[&brake_index]["height"]),
-            brake_width: Length::new::<meter>(brakes[&brake_index]["width"]),
-            brake_thickness: Length::new::<meter>(brakes[&brake_index]["thickness"]),
-            rear_suspension_spring_constant: suspensions[&suspension_index]["spring_constant"],
-            rear_suspension_damping_coefficient: suspensions[&suspension_index]
-                ["damping_coefficient"],
-            rear_suspension_mass: Mass::new::<kilogram>(suspensions[&suspension_index]["mass"]),
-            front_suspension_spring_constant: suspensions[&suspension_index]["spring_constant"],
-            front_suspension_damping_coefficient: suspensions[&suspension_index]
-                ["damping_coefficient"],
-            front_suspension_mass: Mass::new::<kilogram>(suspensions[&suspension_index]["mass"]),
-
             // Parameters with variables bounds
             rear_wing_width: Length::new::<meter>(utils::random_uniform(
+                rng,
                 0.3,
-                9.0 - 2.0 * tires[&rear_tire_index]["radius"],
+                9.0 - 2.0 * rear_tire.radius.get::<meter>(),
             )),
             rear_wing_y_position: Length::new::<meter>(utils::random_uniform(
+                rng,
                 0.5 + rear_wing_height / 2.0,
                 1.2 - rear_wing_height / 2.0,
             )),
+            // The original problem drew this from [0.03 + front_wing_height, 0.25 -
+            // rear_wing_height / 2], which is empty for tall wings and disagrees with the linear
+            // constraint on the front wing's clearance, so it is centered like the side wings
             front_wing_y_position: Length::new::<meter>(utils::random_uniform(
-                0.03 + front_wing_height,
-                0.25 - rear_wing_height / 2.0,
+                rng,
+                0.03 + front_wing_height / 2.0,
+                0.25 - front_wing_height / 2.0,
             )),
             side_wing_y_position: Length::new::<meter>(utils::random_uniform(
+                rng,
                 0.03 + side_wings_height / 2.0,
                 0.25 - side_wings_height / 2.0,
             )),
             engine_y_position: Length::new::<meter>(utils::random_uniform(
-                0.03 + motors[&motor_index]["height"] / 2.0,
-                0.5 - motors[&motor_index]["height"] / 2.0,
+                rng,
+                0.03 + motor.height.get::<meter>() / 2.0,
+                0.5 - motor.height.get::<meter>() / 2.0,
             )),
             cabin_y_position: Length::new::<meter>(utils::random_uniform(
+                rng,
                 0.03 + cabin_height / 2.0,
                 1.2 - cabin_height / 2.0,
             )),
             impact_attenuator_length: Length::new::<meter>(utils::random_uniform(
+                rng,
                 0.2,
                 0.7 - front_wing_length,
             )),
             impact_attenuator_y_position: Length::new::<meter>(utils::random_uniform(
+                rng,
                 0.03 + impact_attenuator_height / 2.0,
                 1.2 - impact_attenuator_height / 2.0,
             )),
             rear_suspension_y_position: Length::new::<meter>(utils::random_uniform(
-                tires[&rear_tire_index]["radius"],
-                2.0 * tires[&rear_tire_index]["radius"],
+                rng,
+                rear_tire.radius.get::<meter>(),
+                2.0 * rear_tire.radius.get::<meter>(),
             )),
             front_suspension_y_position: Length::new::<meter>(utils::random_uniform(
-                tires[&front_tire_index]["radius"],
-                2.0 * tires[&front_tire_index]["radius"],
+                rng,
+                front_tire.radius.get::<meter>(),
+                2.0 * front_tire.radius.get::<meter>(),
             )),
+
+            // Components looked up from indices
+            rear_wing_material: materials[rear_wing_material_index].clone(),
+            front_wing_material: materials[front_wing_material_index].clone(),
+            side_wing_material: materials[side_wing_material_index].clone(),
+            cabin_material: materials[cabin_material_index].clone(),
+            impact_attenuator_material: materials[impact_attenuator_material_index].clone(),
+            rear_tire,
+            front_tire,
+            brake: brakes[brake_index].clone(),
+            motor,
+            rear_suspension: suspensions[rear_suspension_index].clone(),
+            front_suspension: suspensions[front_suspension_index].clone(),
         }
     }
 
+    /// The design variables laid out as described by [`Car::parameter_schema`]
     pub fn get_parameter_vector(&self) -> Vec<f64> {
-        vec![
-            self.rear_wing_length.value,
-            self.rear_wing_height.value,
-            self.rear_wing_angle_of_attack.value,
-            self.front_wing_height.value,
-            self.front_wing_length.value,
-            self.front_wing_width.value,
-            self.front_wing_angle_of_attack.value,
-            self.side_wings_height.value,
-            self.side_wings_length.value,
-            self.side_wings_width.value,
-            self.side_wings_angle_of_attack.value,
-            self.rear_tire_pressure.value,
-            self.front_tire_pressure.value,
-            self.cabin_height.value,
-            self.cabin_length.value,
-            self.cabin_width.value,
-            self.cabin_thickness.value,
-            self.impact_attenuator_height.value,
-            self.impact_attenuator_width.value,
-            self.rear_wing_material_index as f64,
-            self.front_wing_material_index as f64,
-            self.side_wing_material_index as f64,
-            self.cabin_material_index as f64,
-            self.impact_attenuator_material_index as f64,
-            self.rear_tire_index as f64,
-            self.front_tire_index as f64,
-            self.brake_index as f64,
-            self.motor_index as f64,
-            self.suspension_index as f64,
-            self.rear_wing_width.value,
-            self.rear_wing_y_position.value,
-            self.front_wing_y_position.value,
-            self.side_wing_y_position.value,
-            self.engine_y_position.value,
-            self.cabin_y_position.value,
-            self.impact_attenuator_length.value,
-            self.impact_attenuator_y_position.value,
-            self.rear_suspension_y_position.value,
-            self.front_suspension_y_position.value,
-        ]
-    }
-
-    pub fn new_from_parameters(p: &Vec<f64>) -> Self {
+        self.parameter_schema()
+            .parameters()
+            .iter()
+            .map(|parameter| self.parameter_value(parameter.name))
+            .collect()
+    }
+
+    /// The layout of this car's parameter vector
+    pub fn parameter_schema(&self) -> ParameterSchema {
+        ParameterSchema::new(&self.catalog, self.variant)
+    }
+
+    /// The value of a design variable in the unit given by the schema
+    fn parameter_value(&self, name: &str) -> f64 {
+        match name {
+            "rear_wing_height" => self.rear_wing_height.get::<meter>(),
+            "rear_wing_length" => self.rear_wing_length.get::<meter>(),
+            "rear_wing_angle_of_attack" => self.rear_wing_angle_of_attack.get::<radian>(),
+            "front_wing_height" => self.front_wing_height.get::<meter>(),
+            "front_wing_length" => self.front_wing_length.get::<meter>(),
+            "front_wing_width" => self.front_wing_width.get::<meter>(),
+            "front_wing_angle_of_attack" => self.front_wing_angle_of_attack.get::<radian>(),
+            "side_wings_height" => self.side_wings_height.get::<meter>(),
+            "side_wings_length" => self.side_wings_length.get::<meter>(),
+            "side_wings_width" => self.side_wings_width.get::<meter>(),
+            "side_wings_angle_of_attack" => self.side_wings_angle_of_attack.get::<radian>(),
+            "rear_tire_pressure" => self.rear_tire_pressure.get::<bar>(),
+            "front_tire_pressure" => self.front_tire_pressure.get::<bar>(),
+            "cabin_height" => self.cabin_height.get::<meter>(),
+            "cabin_length" => self.cabin_length.get::<meter>(),
+            "cabin_width" => self.cabin_width.get::<meter>(),
+            "cabin_thickness" => self.cabin_thickness.get::<meter>(),
+            "impact_attenuator_height" => self.impact_attenuator_height.get::<meter>(),
+            "impact_attenuator_width" => self.impact_attenuator_width.get::<meter>(),
+            "rear_wing_material_index" => self.rear_wing_material_index as f64,
+            "front_wing_material_index" => self.front_wing_material_index as f64,
+            "side_wing_material_index" => self.side_wing_material_index as f64,
+            "cabin_material_index" => self.cabin_material_index as f64,
+            "impact_attenuator_material_index" => self.impact_attenuator_material_index as f64,
+            "rear_tire_index" => self.rear_tire_index as f64,
+            "front_tire_index" => self.front_tire_index as f64,
+            "brake_index" => self.brake_index as f64,
+            "motor_index" => self.motor_index as f64,
+            "suspension_index" | "rear_suspension_index" => self.rear_suspension_index as f64,
+            "front_suspension_index" => self.front_suspension_index as f64,
+            "final_drive_ratio" => self.final_drive_ratio,
+            "cell_index" => self.cell_index as f64,
+            "inverter_index" => self.inverter_index as f64,
+            "cells_in_series" => self.pack.as_ref().map_or(0, |pack| pack.series) as f64,
+            "cells_in_parallel" => self.pack.as_ref().map_or(0, |pack| pack.parallel) as f64,
+            "rear_wing_width" => self.rear_wing_width.get::<meter>(),
+            "rear_wing_y_position" => self.rear_wing_y_position.get::<meter>(),
+            "front_wing_y_position" => self.front_wing_y_position.get::<meter>(),
+            "side_wing_y_position" => self.side_wing_y_position.get::<meter>(),
+            "engine_y_position" => self.engine_y_position.get::<meter>(),
+            "cabin_y_position" => self.cabin_y_position.get::<meter>(),
+            "impact_attenuator_length" => self.impact_attenuator_length.get::<meter>(),
+            "impact_attenuator_y_position" => self.impact_attenuator_y_position.get::<meter>(),
+            "rear_suspension_y_position" => self.rear_suspension_y_position.get::<meter>(),
+            "front_suspension_y_position" => self.front_suspension_y_position.get::<meter>(),
+            _ => panic!("no parameter named {}", name),
+        }
+    }
+
+    pub fn new_from_parameters(p: &[f64]) -> Self {
+        Car::new_from_parameters_with_catalog(p, Catalog::shared())
+    }
+
+    /// Build a car from a parameter vector whose discrete variables index into the given catalog
+    pub fn new_from_parameters_with_catalog(p: &[f64], catalog: impl Into<Arc<Catalog>>) -> Self {
+        Car::new_from_parameters_with_variant(p, catalog, ProblemVariant::ORIGINAL)
+    }
+
+    /// Build a car from a parameter vector laid out for the given formulation of the problem
+    ///
+    /// Catalog indices and the cell counts of a battery pack are rounded to the nearest whole
+    /// number and clamped to their bounds, so that the fractional or out-of-range values an
+    /// optimizer may propose still select a component and build a pack. The car's own parameter
+    /// vector then holds the values actually used.
+    pub fn new_from_parameters_with_variant(
+        p: &[f64],
+        catalog: impl Into<Arc<Catalog>>,
+        variant: ProblemVariant,
+    ) -> Self {
         // Get libraries
-        let tires = constants::get_tires();
-        let brakes = constants::get_brakes();
-        let motors = constants::get_motors();
-        let materials = constants::get_materials();
-        let suspensions = constants::get_suspensions();
+        let catalog = catalog.into();
+        let electric = variant.powertrain == Powertrain::Electric;
+        let tires = catalog.tires();
+        let brakes = catalog.brakes();
+        let motors = if electric {
+            catalog.electric_motors()
+        } else {
+            catalog.motors()
+        };
+        let materials = catalog.materials();
+        let suspensions = catalog.suspensions();
 
         // Pull out indices
-        let rear_wing_material_index = p[19] as usize;
-        let front_wing_material_index = p[20] as usize;
-        let side_wing_material_index = p[21] as usize;
-        let cabin_material_index = p[22] as usize;
-        let impact_attenuator_material_index = p[23] as usize;
-        let rear_tire_index = p[24] as usize;
-        let front_tire_index = p[25] as usize;
-        let brake_index = p[26] as usize;
-        let motor_index = p[27] as usize;
-        let suspension_index = p[28] as usize;
+        let schema = ParameterSchema::new(&catalog, variant);
+        let value = |name: &str| schema.value(p, name);
+        let integer = |name: &str| {
+            let [lower, upper] = schema.get(name).and_then(|p| p.bounds).unwrap_or_default();
+            value(name).round().clamp(lower, upper) as usize
+        };
+        let index = integer;
+        let rear_wing_material_index = index("rear_wing_material_index");
+        let front_wing_material_index = index("front_wing_material_index");
+        let side_wing_material_index = index("side_wing_material_index");
+        let cabin_material_index = index("cabin_material_index");
+        let impact_attenuator_material_index = index("impact_attenuator_material_index");
+        let rear_tire_index = index("rear_tire_index");
+        let front_tire_index = index("front_tire_index");
+        let brake_index = index("brake_index");
+        let motor_index = index("motor_index");
+        let (rear_suspension_index, front_suspension_index) = if variant.independent_suspension {
+            (
+                index("rear_suspension_index"),
+                index("front_suspension_index"),
+            )
+        } else {
+            (index("suspension_index"), index("suspension_index"))
+        };
+        let final_drive_ratio = if variant.variable_final_drive {
+            value("final_drive_ratio")
+        } else {
+            constants::FINAL_DRIVE_RATIO
+        };
+        let (cell_index, inverter_index, pack, inverter) = if electric {
+            let (cell_index, inverter_index) = (index("cell_index"), index("inverter_index"));
+            let pack = BatteryPack {
+                cell: catalog.cells()[cell_index].clone(),
+                series: integer("cells_in_series"),
+                parallel: integer("cells_in_parallel"),
+            };
+            let inverter = catalog.inverters()[inverter_index].clone();
+            (cell_index, inverter_index, Some(pack), Some(inverter))
+        } else {
+            (0, 0, None, None)
+        };
 
         Car {
+            catalog: catalog.clone(),
+            variant,
+            weights: WeightScenario::default(),
+            normalization: None,
+            conditions: OperatingConditions::default(),
+            competition: None,
+            energy_weight: None,
             rear_wing_material_index,
             front_wing_material_index,
             side_wing_material_index,
@@ -379,397 +590,644 @@ impl Car {
             front_tire_index,
             brake_index,
             motor_index,
-            suspension_index,
-            rear_wing_height: p[0],
-            rear_wing_length: p[1],
-            rear_wing_angle_of_attack: p[2],
-            front_wing_height: p[3],
-            front_wing_length: p[4],
-            front_wing_width: p[5],
-            front_wing_angle_of_attack: p[6],
-            side_wings_height: p[7],
-            side_wings_length: p[8],
-            side_wings_width: p[9],
-            side_wings_angle_of_attack: p[10],
-            rear_tire_pressure: p[11],
-            front_tire_pressure: p[12],
-            cabin_height: p[13],
-            cabin_length: p[14],
-            cabin_width: p[15],
-            cabin_thickness: p[16],
-            impact_attenuator_height: p[17],
-            impact_attenuator_width: p[18],
-            rear_wing_density: materials[&rear_wing_material_index]["density"],
-            front_wing_density: materials[&front_wing_material_index]["density"],
-            side_wing_density: materials[&side_wing_material_index]["density"],
-            cabin_density: materials[&cabin_material_index]["density"],
-            impact_attenuator_density: materials[&impact_attenuator_material_index]["density"],
-            impact_attenuator_modulus: materials[&impact_attenuator_material_index]["modulus"],
-            rear_tire_radius: tires[&rear_tire_index]["radius"],
-            rear_tire_mass: tires[&rear_tire_index]["mass"],
-            front_tire_radius: tires[&front_tire_index]["radius"],
-            front_tire_mass: tires[&front_tire_index]["mass"],
-            engine_power: motors[&motor_index]["power"],
-            engine_length: motors[&motor_index]["length"],
-            engine_height: motors[&motor_index]["height"],
-            engine_torque: motors[&motor_index]["torque"],
-            engine_mass: motors[&motor_index]["mass"],
-            brake_radius: brakes[&brake_index]["radius"],
-            brake_density: brakes[&brake_index]["density"],
-            brake_length: brakes[&brake_index]["length"],
-            brake_height: brakes[&brake_index]["height"],
-            brake_width: brakes[&brake_index]["width"],
-            brake_thickness: brakes[&brake_index]["thickness"],
-            rear_suspension_spring_constant: suspensions[&suspension_index]["spring_constant"],
-            rear_suspension_damping_coefficient: suspensions[&suspension_index]
-                ["damping_coefficient"],
-            rear_suspension_mass: suspensions[&suspension_index]["mass"],
-            front_suspension_spring_constant: suspensions[&suspension_index]["spring_constant"],
-            front_suspension_damping_coefficient: suspensions[&suspension_index]
-                ["damping_coefficient"],
-            front_suspension_mass: suspensions[&suspension_index]["mass"],
-            rear_wing_width: p[29],
-            rear_wing_y_position: p[30],
-            front_wing_y_position: p[31],
-            side_wing_y_position: p[32],
-            engine_y_position: p[33],
-            cabin_y_position: p[34],
-            impact_attenuator_length: p[35],
-            impact_attenuator_y_position: p[36],
-            rear_suspension_y_position: p[37],
-            front_suspension_y_position: p[38],
+            rear_suspension_index,
+            front_suspension_index,
+            final_drive_ratio,
+            cell_index,
+            inverter_index,
+            pack,
+            inverter,
+            rear_wing_height: Length::new::<meter>(value("rear_wing_height")),
+            rear_wing_length: Length::new::<meter>(value("rear_wing_length")),
+            rear_wing_angle_of_attack: Angle::new::<radian>(value("rear_wing_angle_of_attack")),
+            front_wing_height: Length::new::<meter>(value("front_wing_height")),
+            front_wing_length: Length::new::<meter>(value("front_wing_length")),
+            front_wing_width: Length::new::<meter>(value("front_wing_width")),
+            front_wing_angle_of_attack: Angle::new::<radian>(value("front_wing_angle_of_attack")),
+            side_wings_height: Length::new::<meter>(value("side_wings_height")),
+            side_wings_length: Length::new::<meter>(value("side_wings_length")),
+            side_wings_width: Length::new::<meter>(value("side_wings_width")),
+            side_wings_angle_of_attack: Angle::new::<radian>(value("side_wings_angle_of_attack")),
+            rear_tire_pressure: Pressure::new::<bar>(value("rear_tire_pressure")),
+            front_tire_pressure: Pressure::new::<bar>(value("front_tire_pressure")),
+            cabin_height: Length::new::<meter>(value("cabin_height")),
+            cabin_length: Length::new::<meter>(value("cabin_length")),
+            cabin_width: Length::new::<meter>(value("cabin_width")),
+            cabin_thickness: Length::new::<meter>(value("cabin_thickness")),
+            impact_attenuator_height: Length::new::<meter>(value("impact_attenuator_height")),
+            impact_attenuator_width: Length::new::<meter>(value("impact_attenuator_width")),
+            rear_wing_material: materials[rear_wing_material_index].clone(),
+            front_wing_material: materials[front_wing_material_index].clone(),
+            side_wing_material: materials[side_wing_material_index].clone(),
+            cabin_material: materials[cabin_material_index].clone(),
+            impact_attenuator_material: materials[impact_attenuator_material_index].clone(),
+            rear_tire: tires[rear_tire_index].clone(),
+            front_tire: tires[front_tire_index].clone(),
+            brake: brakes[brake_index].clone(),
+            motor: motors[motor_index].clone(),
+            rear_suspension: suspensions[rear_suspension_index].clone(),
+            front_suspension: suspensions[front_suspension_index].clone(),
+            rear_wing_width: Length::new::<meter>(value("rear_wing_width")),
+            rear_wing_y_position: Length::new::<meter>(value("rear_wing_y_position")),
+            front_wing_y_position: Length::new::<meter>(value("front_wing_y_position")),
+            side_wing_y_position: Length::new::<meter>(value("side_wing_y_position")),
+            engine_y_position: Length::new::<meter>(value("engine_y_position")),
+            cabin_y_position: Length::new::<meter>(value("cabin_y_position")),
+            impact_attenuator_length: Length::new::<meter>(value("impact_attenuator_length")),
+            impact_attenuator_y_position: Length::new::<meter>(value(
+                "impact_attenuator_y_position",
+            )),
+            rear_suspension_y_position: Length::new::<meter>(value("rear_suspension_y_position")),
+            front_suspension_y_position: Length::new::<meter>(value("front_suspension_y_position")),
         }
     }
 
-    pub fn objective(&self, weights: [f64; 11]) -> f64 {
-        weights[0] * self.mass()
-            + weights[1] * self.center_of_gravity()
-            + weights[2] * self.total_drag_force()
-            + weights[3] * self.total_downward_force()
-            + weights[4] * self.acceleration()
-            + weights[5] * self.crash_force()
-            + weights[6] * self.impact_attenuator_volume()
-            + weights[7] * self.corner_velocity()
-            + weights[8] * self.brakeing_distance()
-            + weights[9] * self.suspension_acceleration()
-            + weights[10] * self.pitch_moment()
-    }
-
-    pub fn objectives(&self) -> [f64; 11] {
-        [
-            self.mass(),
-            self.center_of_gravity(),
-            self.total_drag_force(),
-            self.total_downward_force(),
-            self.acceleration(),
-            self.crash_force(),
-            self.impact_attenuator_volume(),
-            self.corner_velocity(),
-            self.brakeing_distance(),
-            self.suspension_acceleration(),
-            self.pitch_moment(),
-        ]
+    /// The catalog that this car's components were chosen from
+    pub fn catalog(&self) -> &Catalog {
+        &self.catalog
+    }
+
+    /// The formulation of the problem that this car belongs to
+    pub fn variant(&self) -> ProblemVariant {
+        self.variant
+    }
+
+    /// Use the given weights when this car is evaluated as an argmin operator
+    pub fn with_weights(mut self, weights: impl Into<WeightScenario>) -> Self {
+        self.weights = weights.into();
+        self
+    }
+
+    /// The weights used when this car is evaluated as an argmin operator
+    pub fn weights(&self) -> WeightScenario {
+        self.weights
+    }
+
+    /// Normalize the objectives with the given reference points in [`Car::objective`]
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// The reference points used by [`Car::objective`], if any
+    pub fn normalization(&self) -> Option<&Normalization> {
+        self.normalization.as_ref()
+    }
+
+    /// Evaluate the objectives under the given operating conditions
+    pub fn with_conditions(mut self, conditions: OperatingConditions) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
+    /// The operating conditions under which the objectives are evaluated
+    pub fn conditions(&self) -> &OperatingConditions {
+        &self.conditions
+    }
+
+    /// Score the dynamic events of the given competition, and minimize the negated total points
+    /// instead of the weighted sum when this car is evaluated as an argmin operator
+    pub fn with_competition(mut self, competition: Competition) -> Self {
+        self.competition = Some(Arc::new(competition));
+        self
+    }
+
+    /// The competition given by [`Car::with_competition`], if any
+    pub fn competition(&self) -> Option<&Competition> {
+        self.competition.as_deref()
+    }
+
+    /// Evaluate the energy consumed over the endurance event as a twelfth objective, added to
+    /// the weighted sum of [`Car::objective`] with the given weight
+    pub fn with_energy_objective(mut self, weight: f64) -> Self {
+        self.energy_weight = Some(weight);
+        self
     }
 
+    /// The weight given by [`Car::with_energy_objective`], if any
+    pub fn energy_weight(&self) -> Option<f64> {
+        self.energy_weight
+    }
+
+    /// The weighted sum of [`Objectives::to_minimization_vector`], to be minimized
+    ///
+    /// Either a [`WeightScenario`] or a plain array of 11 weights may be given. If the car has a
+    /// [`Normalization`], the objectives are normalized before they are weighted. The energy
+    /// objective, if enabled, is added with its own weight, and is normalized too when the
+    /// normalization has an energy range. A car that cannot finish the endurance event consumes
+    /// infinite energy, so any positive energy weight makes its objective infinite.
+    pub fn objective(&self, weights: impl Into<WeightScenario>) -> f64 {
+        let objectives = self.objectives();
+        let values = match &self.normalization {
+            Some(normalization) => normalization.normalize(&objectives),
+            None => objectives.to_minimization_vector(),
+        };
+        let energy = match (self.energy_weight, objectives.energy) {
+            (Some(weight), Some(energy)) if !energy.value.is_finite() && weight > 0.0 => {
+                f64::INFINITY
+            }
+            (Some(weight), Some(energy)) if energy.value.is_finite() => {
+                let value = self
+                    .normalization
+                    .and_then(|normalization| normalization.normalize_energy(energy))
+                    .unwrap_or_else(|| energy.get::<joule>());
+                weight * value
+            }
+            _ => 0.0,
+        };
+        weights
+            .into()
+            .weights()
+            .iter()
+            .zip(values.iter())
+            .map(|(weight, objective)| weight * objective)
+            .sum::<f64>()
+            + energy
+    }
+
+    /// The 11 objectives of this car, and the energy consumed if enabled with
+    /// [`Car::with_energy_objective`]
+    pub fn objectives(&self) -> Objectives {
+        Objectives {
+            mass: self.mass(),
+            center_of_gravity: self.center_of_gravity(),
+            drag_force: self.total_drag_force(),
+            downforce: self.total_downward_force(),
+            acceleration: self.acceleration(),
+            crash_force: self.crash_force(),
+            impact_attenuator_volume: self.impact_attenuator_volume(),
+            corner_velocity: self.corner_velocity(),
+            braking_distance: self.brakeing_distance(),
+            suspension_acceleration: self.suspension_acceleration(),
+            pitch_moment: self.pitch_moment(),
+            energy: self.energy_weight.map(|_| self.endurance_consumption()),
+        }
+    }
+
+    /// The signed amount by which each entry of the parameter vector lies outside its bounds
+    ///
+    /// Entries are negative below the lower bound, positive above the upper bound and zero
+    /// otherwise. See [`BoundsReport::from_parameters`] for which bounds apply.
     pub fn check_bounds(&self) -> Vec<f64> {
-        todo!()
+        self.bounds_report().amounts
+    }
+
+    /// The bound check of [`Car::check_bounds`], naming each offending variable
+    pub fn bounds_report(&self) -> BoundsReport {
+        BoundsReport::from_parameters(&self.get_parameter_vector(), &self.catalog, self.variant)
     }
 
+    /// The values g(x) of [`Car::nonlinear_constraints`], each of which should be at most zero
     pub fn check_nonlinear_constraints(&self) -> Vec<f64> {
-        todo!()
+        self.nonlinear_constraints()
+            .iter()
+            .map(|constraint| constraint.value)
+            .collect()
+    }
+
+    /// The bounds that depend on the chosen components, as g(x) <= 0
+    ///
+    /// Bounds that are linear in the parameter vector are in [`Car::linear_constraint_matrix`].
+    pub fn nonlinear_constraints(&self) -> Vec<Constraint> {
+        let rear_tire_radius = self.rear_tire.radius.value;
+        let front_tire_radius = self.front_tire.radius.value;
+        let mut constraints = vec![
+            Constraint::at_most(
+                "rear_wing_width <= 9.0 - 2 rear_tire_radius",
+                self.rear_wing_width.value,
+                9.0 - 2.0 * rear_tire_radius,
+            ),
+            Constraint::at_least(
+                "engine_y_position >= 0.03 + engine_height / 2",
+                self.engine_y_position.value,
+                0.03 + self.motor.height.value / 2.0,
+            ),
+            Constraint::at_most(
+                "engine_y_position <= 0.5 - engine_height / 2",
+                self.engine_y_position.value,
+                0.5 - self.motor.height.value / 2.0,
+            ),
+            Constraint::at_least(
+                "rear_suspension_y_position >= rear_tire_radius",
+                self.rear_suspension_y_position.value,
+                rear_tire_radius,
+            ),
+            Constraint::at_most(
+                "rear_suspension_y_position <= 2 rear_tire_radius",
+                self.rear_suspension_y_position.value,
+                2.0 * rear_tire_radius,
+            ),
+            Constraint::at_least(
+                "front_suspension_y_position >= front_tire_radius",
+                self.front_suspension_y_position.value,
+                front_tire_radius,
+            ),
+            Constraint::at_most(
+                "front_suspension_y_position <= 2 front_tire_radius",
+                self.front_suspension_y_position.value,
+                2.0 * front_tire_radius,
+            ),
+        ];
+        constraints.extend(self.electric_constraints());
+        constraints
     }
 
+    /// The values A·x - b of [`Car::linear_constraint_matrix`], each of which should be at most zero
     pub fn check_linear_constraints(&self) -> Vec<f64> {
-        todo!()
+        self.linear_constraint_matrix()
+            .evaluate(&self.get_parameter_vector())
     }
 
-    pub fn center_of_gravity(&self) -> f64 {
+    /// The rows of [`Car::check_linear_constraints`], with names
+    pub fn linear_constraints(&self) -> Vec<Constraint> {
+        let matrix = self.linear_constraint_matrix();
+        let values = matrix.evaluate(&self.get_parameter_vector());
+        matrix
+            .names
+            .into_iter()
+            .zip(values)
+            .map(|(name, value)| Constraint { name, value })
+            .collect()
+    }
+
+    /// The linear constraints of the problem as an explicit system A·x <= b
+    pub fn linear_constraint_matrix(&self) -> LinearConstraints {
+        LinearConstraints::new(&self.parameter_schema())
+    }
+
+    // # objective 2 - center of gravity height (minimize)
+    pub fn center_of_gravity(&self) -> Length {
         let total_mass = self.mass();
         let t1 = (self.mass_rear_wing() * self.rear_wing_y_position
             + self.mass_front_wing() * self.front_wing_y_position
-            + self.engine_mass * self.engine_y_position
+            + self.motor.mass * self.engine_y_position
             + self.mass_cabin() * self.cabin_y_position
-            + self.mass_impact_attenuator() * self.impact_attenuator_y_position)
+            + self.mass_impact_attenuator() * self.impact_attenuator_y_position
+            + self.pack_mass() * Length::new::<meter>(constants::PACK_Y_POSITION)
+            + self.mass_inverter() * self.engine_y_position)
             / total_mass;
         let t2 = 2.0
             * (self.mass_side_wings() * self.side_wing_y_position
-                + self.rear_tire_mass * self.rear_tire_radius
-                + self.front_tire_mass * self.front_tire_radius
-                + self.mass_brake() * self.front_tire_radius
-                + self.rear_suspension_mass * self.rear_suspension_y_position
-                + self.front_suspension_mass * self.front_suspension_y_position)
+                + self.rear_tire.mass * self.rear_tire.radius
+                + self.front_tire.mass * self.front_tire.radius
+                + self.mass_brake() * self.front_tire.radius
+                + self.rear_suspension.rear_mass * self.rear_suspension_y_position
+                + self.front_suspension.front_mass * self.front_suspension_y_position)
             / total_mass;
-        return t1 + t2;
+        t1 + t2
     }
 
-    pub fn mass(&self) -> f64 {
+    // # objective 1 - mass (minimize)
+    pub fn mass(&self) -> Mass {
         self.mass_rear_wing()
             + self.mass_front_wing()
             + 2.0 * self.mass_side_wings()
-            + 2.0 * self.rear_tire_mass
-            + 2.0 * self.front_tire_mass
-            + self.engine_mass
+            + 2.0 * self.rear_tire.mass
+            + 2.0 * self.front_tire.mass
+            + self.motor.mass
             + self.mass_cabin()
             + self.mass_impact_attenuator()
             + 4.0 * self.mass_brake()
-            + 2.0 * self.rear_suspension_mass
-            + 2.0 * self.front_suspension_mass
+            + 2.0 * self.rear_suspension.rear_mass
+            + 2.0 * self.front_suspension.front_mass
+            + self.mass_electrics()
     }
 
-    fn mass_rear_wing(&self) -> f64 {
+    fn mass_rear_wing(&self) -> Mass {
         self.rear_wing_length
             * self.rear_wing_width
             * self.rear_wing_height
-            * self.rear_wing_density
+            * self.rear_wing_material.density
     }
 
-    fn mass_front_wing(&self) -> f64 {
+    fn mass_front_wing(&self) -> Mass {
         self.front_wing_length
             * self.front_wing_width
             * self.front_wing_height
-            * self.front_wing_density
+            * self.front_wing_material.density
     }
 
-    fn mass_side_wings(&self) -> f64 {
+    fn mass_side_wings(&self) -> Mass {
         self.side_wings_length
             * self.side_wings_width
             * self.side_wings_height
-            * self.side_wing_density
+            * self.side_wing_material.density
     }
-    fn mass_impact_attenuator(&self) -> f64 {
+    fn mass_impact_attenuator(&self) -> Mass {
         self.impact_attenuator_length
             * self.impact_attenuator_width
             * self.impact_attenuator_height
-            * self.impact_attenuator_density
+            * self.impact_attenuator_material.density
     }
-    fn mass_cabin(&self) -> f64 {
+    fn mass_cabin(&self) -> Mass {
         2.0 * (self.cabin_height * self.cabin_length * self.cabin_thickness
             + self.cabin_height * self.cabin_width * self.cabin_thickness
             + self.cabin_length * self.cabin_height * self.cabin_thickness)
-            * self.cabin_density
+            * self.cabin_material.density
     }
-    fn mass_brake(&self) -> f64 {
-        self.brake_length * self.brake_width * self.brake_height * self.brake_density
+    fn mass_brake(&self) -> Mass {
+        self.brake.length * self.brake.width * self.brake.height * self.brake.density
     }
 
     // aspect ratio of wing
-    fn aspect_ratio(w: f64, alpha: f64, l: f64) -> f64 {
-        w * alpha.cos() / l
+    fn aspect_ratio(w: Length, alpha: Angle, l: Length) -> f64 {
+        (w * alpha.cos() / l).get::<ratio>()
     }
 
     // lift co-effecient
-    fn lift_coefficient(aspect_ratio: f64, alpha: f64) -> f64 {
-        2.0 * std::f64::consts::PI * (aspect_ratio / (aspect_ratio + 2.0)) * alpha
+    fn lift_coefficient(aspect_ratio: f64, alpha: Angle) -> f64 {
+        2.0 * std::f64::consts::PI * (aspect_ratio / (aspect_ratio + 2.0)) * alpha.get::<radian>()
     }
 
     // drag co-efficient
     fn drag_coefficient(lift_coefficient: f64, aspect_ratio: f64) -> f64 {
-        return lift_coefficient.powi(2) / (std::f64::consts::PI * aspect_ratio);
+        lift_coefficient.powi(2) / (std::f64::consts::PI * aspect_ratio)
     }
 
     // wing downforce
-    fn wing_down_force(&self, w: f64, h: f64, l: f64, alpha: f64) -> f64 {
+    fn wing_down_force(&self, w: Length, h: Length, l: Length, alpha: Angle) -> Force {
         let wing_aspect_ratio = Car::aspect_ratio(w, alpha, l);
         let c_lift = Car::lift_coefficient(wing_aspect_ratio, alpha);
-        0.5 * alpha * h * w * RHO_AIR * (V_CAR.powi(2)) * c_lift
+        0.5 * alpha.get::<radian>()
+            * h
+            * w
+            * self.conditions.air_density
+            * self.conditions.vehicle_speed
+            * self.conditions.vehicle_speed
+            * c_lift
     }
 
     // wing drag
-    fn wing_drag_force(&self, w: f64, h: f64, l: f64, alpha: f64) -> f64 {
+    fn wing_drag_force(&self, w: Length, h: Length, l: Length, alpha: Angle) -> Force {
         let wing_aspect_ratio = Car::aspect_ratio(w, alpha, l);
         let c_lift = Car::lift_coefficient(wing_aspect_ratio, alpha);
         let c_drag = Car::drag_coefficient(c_lift, wing_aspect_ratio);
-        Car::drag_force(w, h, c_drag)
+        self.drag_force(w, h, c_drag)
     }
 
     // drag
-    fn drag_force(w: f64, h: f64, c_drag: f64) -> f64 {
-        return 0.5 * w * h * RHO_AIR * V_CAR.powi(2) * c_drag;
+    fn drag_force(&self, w: Length, h: Length, c_drag: f64) -> Force {
+        0.5 * w
+            * h
+            * self.conditions.air_density
+            * self.conditions.vehicle_speed
+            * self.conditions.vehicle_speed
+            * c_drag
     }
     // # objective 3 - total drag (minimize)
-    fn total_drag_force(&self) -> f64 {
-        let cabinDrag = Car::drag_force(self.cabin_width, self.cabin_height, C_DC);
-        let rearWingDrag = self.wing_drag_force(
+    pub fn total_drag_force(&self) -> Force {
+        let cabin_drag = self.drag_force(
+            self.cabin_width,
+            self.cabin_height,
+            self.conditions.cabin_drag_coefficient,
+        );
+        let rear_wing_drag = self.wing_drag_force(
             self.rear_wing_width,
             self.rear_wing_height,
             self.rear_wing_length,
             self.rear_wing_angle_of_attack,
         );
-        let frontWingDrag = self.wing_drag_force(
+        let front_wing_drag = self.wing_drag_force(
             self.front_wing_width,
             self.front_wing_height,
             self.front_wing_length,
             self.front_wing_angle_of_attack,
         );
-        let sideWingDrag = self.wing_drag_force(
+        let side_wing_drag = self.wing_drag_force(
             self.side_wings_width,
             self.side_wings_height,
             self.side_wings_length,
             self.side_wings_angle_of_attack,
         );
-        rearWingDrag + frontWingDrag + 2.0 * sideWingDrag + cabinDrag
+        rear_wing_drag + front_wing_drag + 2.0 * side_wing_drag + cabin_drag
     }
 
     // # objective 4 - total downforce (maximize)
-    fn total_downward_force(&self) -> f64 {
-        let downForceRearWing = self.wing_down_force(
+    pub fn total_downward_force(&self) -> Force {
+        let down_force_rear_wing = self.wing_down_force(
             self.rear_wing_width,
             self.rear_wing_height,
             self.rear_wing_length,
             self.rear_wing_angle_of_attack,
         );
-        let downForceFrontWing = self.wing_down_force(
+        let down_force_front_wing = self.wing_down_force(
             self.front_wing_width,
             self.front_wing_height,
             self.front_wing_length,
             self.front_wing_angle_of_attack,
         );
-        let downForceSideWing = self.wing_down_force(
+        let down_force_side_wing = self.wing_down_force(
             self.side_wings_width,
             self.side_wings_height,
             self.side_wings_length,
             self.side_wings_angle_of_attack,
         );
-        downForceRearWing + downForceFrontWing + 2.0 * downForceSideWing
+        down_force_rear_wing + down_force_front_wing + 2.0 * down_force_side_wing
+    }
+
+    // rolling resistance coefficient, from the speed in units of 100 km/h and the pressure in bar
+    fn rolling_resistance_coefficient(&self, tire_pressure: Pressure) -> f64 {
+        0.005
+            + 1.0 / tire_pressure.get::<bar>()
+                * (0.01
+                    + 0.0095
+                        * (self.conditions.vehicle_speed.get::<kilometer_per_hour>() / 100.0)
+                            .powi(2))
     }
 
     // rolling resistance
-    fn rolling_resistance(&self, tire_pressure: f64) -> f64 {
-        let c = 0.005 + 1.0 / tire_pressure * (0.01 + 0.0095 * ((V_CAR * 3.6 / 100.0).powi(2)));
-        return c * self.mass() * GRAVITY;
+    fn rolling_resistance(&self, tire_pressure: Pressure) -> Force {
+        self.rolling_resistance_coefficient(tire_pressure) * self.mass() * self.conditions.gravity
+    }
+    // drag and rolling resistance at the operating speed
+    fn total_resistance(&self) -> Force {
+        self.total_drag_force() + self.rolling_resistance(self.rear_tire_pressure)
     }
-    // # objective 5 - acceleration (maximize)
-    fn acceleration(&self) -> f64 {
-        let total_resistance =
-            self.total_drag_force() + self.rolling_resistance(self.rear_tire_pressure);
 
-        let w_wheels = V_CAR / self.rear_tire_radius;
-        let efficiency = total_resistance * V_CAR / self.engine_power;
-        let torque = self.engine_torque;
+    /// The engine speed at which the rear wheels roll at the operating speed
+    pub fn engine_speed(&self) -> AngularVelocity {
+        AngularVelocity::new::<radian_per_second>(
+            self.final_drive_ratio * (self.conditions.vehicle_speed / self.rear_tire.radius).value,
+        )
+    }
 
-        let f_wheels = torque * efficiency * W_E / (self.rear_tire_radius * w_wheels);
+    /// Engine revolutions per revolution of the rear wheels
+    pub fn final_drive_ratio(&self) -> f64 {
+        self.final_drive_ratio
+    }
 
-        (f_wheels - total_resistance) / self.mass()
+    // force delivered by the rear wheels at the operating speed, from the engine torque at the
+    // matching engine speed, capped by the battery and inverter of an electric powertrain and by
+    // the grip of the rear tires
+    fn tractive_force(&self) -> Force {
+        let speed = self.engine_speed();
+        let mut torque = self
+            .motor
+            .torque_curve
+            .torque_at(speed)
+            .get::<newton_meter>();
+        if let Some(limit) = self.power_limit() {
+            torque = torque.min(limit.value / speed.get::<radian_per_second>());
+        }
+        let force = Force::new::<newton>(
+            torque * self.final_drive_ratio / self.rear_tire.radius.get::<meter>(),
+        ) * self.powertrain_efficiency();
+        let traction = self.traction_limit();
+        if force < traction {
+            force
+        } else {
+            traction
+        }
+    }
+
+    // fraction of the engine or battery power that reaches the wheels
+    fn powertrain_efficiency(&self) -> f64 {
+        self.conditions.drivetrain_efficiency
+            * self
+                .inverter
+                .as_ref()
+                .map_or(1.0, |inverter| inverter.efficiency)
+    }
+
+    // # objective 5 - acceleration (maximize)
+    pub fn acceleration(&self) -> Acceleration {
+        (self.tractive_force() - self.total_resistance()) / self.mass()
     }
 
     // # objective 6 - crash force (minimize)
-    fn crash_force(&self) -> f64 {
+    pub fn crash_force(&self) -> Force {
         (self.mass()
-            * V_CAR.powi(2)
+            * self.conditions.vehicle_speed
+            * self.conditions.vehicle_speed
             * self.impact_attenuator_width
             * self.impact_attenuator_height
-            * self.impact_attenuator_modulus
+            * self.impact_attenuator_material.modulus
             / (2.0 * self.impact_attenuator_length))
             .sqrt()
     }
 
     // # objective 7 - impact attenuator volume (minimize)
-    fn impact_attenuator_volume(&self) -> f64 {
+    pub fn impact_attenuator_volume(&self) -> Volume {
         self.impact_attenuator_length * self.impact_attenuator_height * self.impact_attenuator_width
     }
 
-    fn suspension_force(k: f64, c: f64) -> f64 {
-        k * Y_SUSPENSION + c * DYDT_SUSPENSION
+    // force of a spring/damper pair at the displacement and velocity of the operating conditions
+    fn suspension_force(&self, k: SpringRate, c: DampingCoefficient) -> Force {
+        k * self.conditions.suspension_displacement + c * self.conditions.suspension_velocity
     }
     // # objective 8 - corner velocity in skid pad (maximize)
-    fn corner_velocity(&self) -> f64 {
-        let F_fsp = Car::suspension_force(
-            self.front_suspension_spring_constant,
-            self.front_suspension_damping_coefficient,
+    pub fn corner_velocity(&self) -> Velocity {
+        let f_fsp = self.suspension_force(
+            self.front_suspension.front_spring_constant,
+            self.front_suspension.front_damping_coefficient,
         );
-        let F_rsp = Car::suspension_force(
-            self.rear_suspension_spring_constant,
-            self.rear_suspension_damping_coefficient,
+        let f_rsp = self.suspension_force(
+            self.rear_suspension.rear_spring_constant,
+            self.rear_suspension.rear_damping_coefficient,
         );
         let downforce = self.total_downward_force();
-        let mTotal = self.mass();
+        let m_total = self.mass();
 
-        let Clat = 1.6;
-        let forces = downforce + mTotal * GRAVITY - 2.0 * F_fsp - 2.0 * F_rsp;
+        let c_lat = self.lateral_grip();
+        let forces = downforce + m_total * self.conditions.gravity - 2.0 * f_fsp - 2.0 * f_rsp;
 
-        return if forces < 0.0 {
-            0.0
+        if forces.value < 0.0 {
+            Velocity::new::<meter_per_second>(0.0)
         } else {
-            (forces * Clat * R_TRACK / mTotal).sqrt()
-        };
+            (forces * c_lat * self.conditions.skidpad_radius / m_total).sqrt()
+        }
     }
-    // # objective 9 - (minimize)
-    fn brakeing_distance(&self) -> f64 {
-        let mTotal = self.mass();
-        let C = 0.005
-            + 1.0 / self.rear_tire_pressure * (0.01 + 0.0095 * ((V_CAR * 3.6 / 100.0).powi(2)));
+    // # objective 9 - braking distance (minimize)
+    pub fn brakeing_distance(&self) -> Length {
+        self.conditions.vehicle_speed * self.conditions.vehicle_speed
+            / (2.0 * self.braking_deceleration())
+    }
+
+    // deceleration under full braking at the operating speed
+    fn braking_deceleration(&self) -> Acceleration {
+        let m_total = self.mass();
+        let c = self.rolling_resistance_coefficient(self.rear_tire_pressure);
 
-        let A_brk = self.brake_height * self.brake_width;
+        let a_brk = self.brake.height * self.brake.width;
         let c_brk = 0.37;
-        let Tbrk = 2.0 * c_brk * P_BRAKE * A_brk * self.brake_radius;
+        // braking torque, kept as force times lever arm
+        let t_brk = 2.0 * c_brk * self.conditions.brake_pressure * a_brk * self.brake.radius;
 
-        let Ffsp = Car::suspension_force(
-            self.front_suspension_spring_constant,
-            self.front_suspension_damping_coefficient,
+        let f_fsp = self.suspension_force(
+            self.front_suspension.front_spring_constant,
+            self.front_suspension.front_damping_coefficient,
         );
-        let Frsp = Car::suspension_force(
-            self.rear_suspension_spring_constant,
-            self.rear_suspension_damping_coefficient,
+        let f_rsp = self.suspension_force(
+            self.rear_suspension.rear_spring_constant,
+            self.rear_suspension.rear_damping_coefficient,
         );
-        let mut Fy = mTotal * GRAVITY + self.total_downward_force() - 2.0 * Frsp - 2.0 * Ffsp;
-        if Fy <= 0.0 {
-            Fy = 1e-10;
+        let mut f_y = m_total * self.conditions.gravity + self.total_downward_force()
+            - 2.0 * f_rsp
+            - 2.0 * f_fsp;
+        if f_y.value <= 0.0 {
+            f_y = Force::new::<newton>(1e-10);
+        }
+        let braking = f_y * c / m_total + 4.0 * t_brk / (self.rear_tire.radius * m_total);
+        let grip_limit = self.braking_grip_limit();
+        if braking < grip_limit {
+            braking
+        } else {
+            grip_limit
         }
-        let a_brk = Fy * C / mTotal + 4.0 * Tbrk / (self.rear_tire_radius * mTotal);
-        return V_CAR.powi(2) / (2.0 * a_brk);
     }
 
-    // # objective 10 - (minimize)
-    fn suspension_acceleration(&self) -> f64 {
-        let Ffsp = Car::suspension_force(
-            self.front_suspension_spring_constant,
-            self.front_suspension_damping_coefficient,
+    // # objective 10 - suspension acceleration (minimize)
+    pub fn suspension_acceleration(&self) -> Acceleration {
+        let f_fsp = self.suspension_force(
+            self.front_suspension.front_spring_constant,
+            self.front_suspension.front_damping_coefficient,
         );
-        let Frsp = Car::suspension_force(
-            self.rear_suspension_spring_constant,
-            self.rear_suspension_damping_coefficient,
+        let f_rsp = self.suspension_force(
+            self.rear_suspension.rear_spring_constant,
+            self.rear_suspension.rear_damping_coefficient,
         );
-        let mTotal = self.mass();
-        let Fd = self.total_downward_force();
-        return -(2.0 * Ffsp - 2.0 * Frsp - mTotal * GRAVITY - Fd) / mTotal;
+        let m_total = self.mass();
+        let f_d = self.total_downward_force();
+        -(2.0 * f_fsp - 2.0 * f_rsp - m_total * self.conditions.gravity - f_d) / m_total
     }
 
-    // # objective 11 - (minimize)
-    fn pitch_moment(&self) -> f64 {
-        let Ffsp = Car::suspension_force(
-            self.front_suspension_spring_constant,
-            self.front_suspension_damping_coefficient,
+    // # objective 11 - pitch moment (minimize)
+    pub fn pitch_moment(&self) -> Torque {
+        let f_fsp = self.suspension_force(
+            self.front_suspension.front_spring_constant,
+            self.front_suspension.front_damping_coefficient,
         );
-        let Frsp = Car::suspension_force(
-            self.rear_suspension_spring_constant,
-            self.rear_suspension_damping_coefficient,
+        let f_rsp = self.suspension_force(
+            self.rear_suspension.rear_spring_constant,
+            self.rear_suspension.rear_damping_coefficient,
         );
-        let downForceRearWing = self.wing_down_force(
+        let down_force_rear_wing = self.wing_down_force(
             self.rear_wing_width,
             self.rear_wing_height,
             self.rear_wing_length,
             self.rear_wing_angle_of_attack,
         );
-        let downForceFrontWing = self.wing_down_force(
+        let down_force_front_wing = self.wing_down_force(
             self.front_wing_width,
             self.front_wing_height,
             self.front_wing_length,
             self.front_wing_angle_of_attack,
         );
-        let downForceSideWing = self.wing_down_force(
+        let down_force_side_wing = self.wing_down_force(
             self.side_wings_width,
             self.side_wings_height,
             self.side_wings_length,
             self.side_wings_angle_of_attack,
         );
         let lcg = self.cabin_length;
-        let lf = 0.5;
-        return (2.0 * Ffsp * lf
-            + 2.0 * Frsp * lf
-            + downForceRearWing * (lcg - self.rear_wing_length)
-            - downForceFrontWing * (lcg - self.front_wing_length)
-            - 2.0 * downForceSideWing * (lcg - self.side_wings_length))
-            .abs();
+        let lf = Length::new::<meter>(0.5);
+        let moment = 2.0 * f_fsp * lf
+            + 2.0 * f_rsp * lf
+            + down_force_rear_wing * (lcg - self.rear_wing_length)
+            - down_force_front_wing * (lcg - self.front_wing_length)
+            - 2.0 * down_force_side_wing * (lcg - self.side_wings_length);
+        // uom keeps torque apart from energy, so convert through the SI value
+        Torque::new::<newton_meter>(moment.value.abs())
     }
 }
 
@@ -781,25 +1239,213 @@ impl argmin::prelude::ArgminOp for Car {
     type Float = f64;
 
     fn apply(&self, param: &Self::Param) -> Result<Self::Output, argmin::prelude::Error> {
-        Ok(Car::new_from_parameters(param).objective(EVEN_WEIGHTS))
+        let expected = self.parameter_schema().len();
+        if param.len() != expected {
+            return Err(argmin::prelude::ArgminError::InvalidParameter {
+                text: format!("expected {} parameters, got {}", expected, param.len()),
+            }
+            .into());
+        }
+        let car = Car {
+            weights: self.weights,
+            normalization: self.normalization,
+            conditions: self.conditions,
+            competition: self.competition.clone(),
+            energy_weight: self.energy_weight,
+            ..Car::new_from_parameters_with_variant(param, self.catalog.clone(), self.variant)
+        };
+        Ok(match self.competition {
+            Some(_) => car.dynamic_event_objective(),
+            None => car.objective(self.weights),
+        })
     }
 }
 
 impl std::fmt::Display for Car {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        todo!()
+        fn line(
+            f: &mut std::fmt::Formatter<'_>,
+            name: &str,
+            value: f64,
+            unit: &str,
+        ) -> std::fmt::Result {
+            writeln!(f, "  {:<30}{:>12.4} {}", name, value, unit)
+        }
+        fn component(f: &mut std::fmt::Formatter<'_>, name: &str, id: &str) -> std::fmt::Result {
+            writeln!(f, "  {:<30}{:>12}", name, id)
+        }
+
+        writeln!(f, "Rear wing")?;
+        component(f, "material", &self.rear_wing_material.code)?;
+        line(f, "height", self.rear_wing_height.get::<meter>(), "m")?;
+        line(f, "length", self.rear_wing_length.get::<meter>(), "m")?;
+        line(f, "width", self.rear_wing_width.get::<meter>(), "m")?;
+        line(
+            f,
+            "angle of attack",
+            self.rear_wing_angle_of_attack.get::<radian>(),
+            "rad",
+        )?;
+        line(
+            f,
+            "y position",
+            self.rear_wing_y_position.get::<meter>(),
+            "m",
+        )?;
+
+        writeln!(f, "Front wing")?;
+        component(f, "material", &self.front_wing_material.code)?;
+        line(f, "height", self.front_wing_height.get::<meter>(), "m")?;
+        line(f, "length", self.front_wing_length.get::<meter>(), "m")?;
+        line(f, "width", self.front_wing_width.get::<meter>(), "m")?;
+        line(
+            f,
+            "angle of attack",
+            self.front_wing_angle_of_attack.get::<radian>(),
+            "rad",
+        )?;
+        line(
+            f,
+            "y position",
+            self.front_wing_y_position.get::<meter>(),
+            "m",
+        )?;
+
+        writeln!(f, "Side wings")?;
+        component(f, "material", &self.side_wing_material.code)?;
+        line(f, "height", self.side_wings_height.get::<meter>(), "m")?;
+        line(f, "length", self.side_wings_length.get::<meter>(), "m")?;
+        line(f, "width", self.side_wings_width.get::<meter>(), "m")?;
+        line(
+            f,
+            "angle of attack",
+            self.side_wings_angle_of_attack.get::<radian>(),
+            "rad",
+        )?;
+        line(
+            f,
+            "y position",
+            self.side_wing_y_position.get::<meter>(),
+            "m",
+        )?;
+
+        writeln!(f, "Cabin")?;
+        component(f, "material", &self.cabin_material.code)?;
+        line(f, "height", self.cabin_height.get::<meter>(), "m")?;
+        line(f, "length", self.cabin_length.get::<meter>(), "m")?;
+        line(f, "width", self.cabin_width.get::<meter>(), "m")?;
+        line(f, "thickness", self.cabin_thickness.get::<meter>(), "m")?;
+        line(f, "y position", self.cabin_y_position.get::<meter>(), "m")?;
+
+        writeln!(f, "Impact attenuator")?;
+        component(f, "material", &self.impact_attenuator_material.code)?;
+        line(
+            f,
+            "height",
+            self.impact_attenuator_height.get::<meter>(),
+            "m",
+        )?;
+        line(
+            f,
+            "length",
+            self.impact_attenuator_length.get::<meter>(),
+            "m",
+        )?;
+        line(f, "width", self.impact_attenuator_width.get::<meter>(), "m")?;
+        line(
+            f,
+            "y position",
+            self.impact_attenuator_y_position.get::<meter>(),
+            "m",
+        )?;
+
+        writeln!(f, "Tires")?;
+        component(f, "rear", &self.rear_tire.id)?;
+        line(
+            f,
+            "rear pressure",
+            self.rear_tire_pressure.get::<bar>(),
+            "bar",
+        )?;
+        component(f, "front", &self.front_tire.id)?;
+        line(
+            f,
+            "front pressure",
+            self.front_tire_pressure.get::<bar>(),
+            "bar",
+        )?;
+
+        writeln!(f, "Brakes")?;
+        component(f, "brake", &self.brake.id)?;
+
+        writeln!(f, "Motor")?;
+        component(
+            f,
+            "motor",
+            &format!("{} ({})", self.motor.name, self.motor.id),
+        )?;
+        line(f, "final drive ratio", self.final_drive_ratio, "")?;
+
+        if let (Some(pack), Some(inverter)) = (&self.pack, &self.inverter) {
+            writeln!(f, "Battery")?;
+            component(f, "cell", &format!("{} ({})", pack.cell.name, pack.cell.id))?;
+            component(f, "cells in series", &pack.series.to_string())?;
+            component(f, "cells in parallel", &pack.parallel.to_string())?;
+            line(f, "voltage", pack.voltage().value, "V")?;
+            line(f, "energy", pack.energy().get::<kilowatt_hour>(), "kWh")?;
+            line(f, "mass", self.pack_mass().get::<kilogram>(), "kg")?;
+            component(
+                f,
+                "inverter",
+                &format!("{} ({})", inverter.name, inverter.id),
+            )?;
+        }
+        line(f, "y position", self.engine_y_position.get::<meter>(), "m")?;
+
+        writeln!(f, "Suspension")?;
+        component(f, "rear", &self.rear_suspension.id)?;
+        line(
+            f,
+            "rear y position",
+            self.rear_suspension_y_position.get::<meter>(),
+            "m",
+        )?;
+        component(f, "front", &self.front_suspension.id)?;
+        line(
+            f,
+            "front y position",
+            self.front_suspension_y_position.get::<meter>(),
+            "m",
+        )?;
+
+        writeln!(f, "Objectives")?;
+        let objectives = self.objectives();
+        for (i, value) in objectives.to_vector().iter().enumerate() {
+            let name = Objectives::NAMES[i].replace('_', " ");
+            line(f, &name, *value, Objectives::UNITS[i])?;
+        }
+        if let Some(energy) = objectives.energy {
+            line(
+                f,
+                Objectives::ENERGY_NAME,
+                energy.get::<joule>(),
+                Objectives::ENERGY_UNIT,
+            )?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
 
     #[test]
     fn internal() {
-        let cost = Car::new();
-        println!("{:?}", cost.objective(EVEN_WEIGHTS));
-        let init_param: Vec<f64> = vec![0.0; 39];
+        let cost = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        println!("{:?}", cost.objective(WeightScenario::Even));
+        // let init_param: Vec<f64> = vec![0.0; 39];
         // let line_search = argmin::solver::linesearch::MoreThuenteLineSearch::new();
         // let solver = argmin::solver::gradientdescent::SteepestDescent::new(line_search);
         // let res = argmin::prelude::Executor::new(cost, solver, init_param)
@@ -810,4 +1456,350 @@ mod tests {
         //     .max_iters(10)
         //     .run()?;
     }
+
+    #[test]
+    fn nonlinear_constraints() {
+        let mut car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        assert!(car
+            .nonlinear_constraints()
+            .iter()
+            .all(Constraint::is_satisfied));
+
+        car.rear_suspension_y_position = car.rear_tire.radius * 3.0;
+        let violated: Vec<&str> = car
+            .nonlinear_constraints()
+            .into_iter()
+            .filter(|constraint| !constraint.is_satisfied())
+            .map(|constraint| constraint.name)
+            .collect();
+        assert_eq!(
+            violated,
+            ["rear_suspension_y_position <= 2 rear_tire_radius"]
+        );
+    }
+
+    #[test]
+    fn linear_constraints() {
+        let mut car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        assert!(car.check_linear_constraints().iter().all(|&g| g <= 0.0));
+        assert_eq!(car.linear_constraint_matrix().a[0].len(), 39);
+
+        car.impact_attenuator_length = Length::new::<meter>(0.3);
+        car.front_wing_length = Length::new::<meter>(0.5);
+        let violated: Vec<&str> = car
+            .linear_constraints()
+            .into_iter()
+            .filter(|constraint| !constraint.is_satisfied())
+            .map(|constraint| constraint.name)
+            .collect();
+        assert_eq!(
+            violated,
+            ["impact_attenuator_length <= 0.7 - front_wing_length"]
+        );
+    }
+
+    #[test]
+    fn parameter_round_trip() {
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let p = car.get_parameter_vector();
+        assert_eq!(p.len(), car.parameter_schema().len());
+        assert_eq!(p[0], car.rear_wing_height.get::<meter>());
+
+        let rebuilt = Car::new_from_parameters(&p);
+        for (a, b) in rebuilt.get_parameter_vector().iter().zip(&p) {
+            assert!((a - b).abs() <= 1e-12 * b.abs());
+        }
+        assert_eq!(rebuilt.rear_wing_length, car.rear_wing_length);
+        assert_eq!(rebuilt.rear_wing_material, car.rear_wing_material);
+        assert_eq!(rebuilt.rear_tire, car.rear_tire);
+        assert_eq!(rebuilt.brake, car.brake);
+        assert_eq!(rebuilt.motor, car.motor);
+        assert_eq!(rebuilt.rear_suspension, car.rear_suspension);
+        for (a, b) in rebuilt
+            .objectives()
+            .to_vector()
+            .iter()
+            .zip(&car.objectives().to_vector())
+        {
+            assert!((a - b).abs() <= 1e-9 * b.abs());
+        }
+
+        // Fractional and out-of-range catalog indices select the nearest catalog entry
+        let schema = car.parameter_schema();
+        let (tire, motor) = (
+            schema.index_of("rear_tire_index").unwrap(),
+            schema.index_of("motor_index").unwrap(),
+        );
+        let mut q = p.clone();
+        q[tire] = 1.6;
+        q[motor] = 99.0;
+        let clamped = Car::new_from_parameters(&q);
+        assert_eq!(clamped.rear_tire, Catalog::builtin().tires()[2]);
+        assert_eq!(clamped.motor, *Catalog::builtin().motors().last().unwrap());
+        assert!(clamped.bounds_report().is_feasible());
+        q[tire] = -3.0;
+        assert_eq!(
+            Car::new_from_parameters(&q).rear_tire,
+            Catalog::builtin().tires()[0]
+        );
+    }
+
+    #[test]
+    fn seeded() {
+        let a = Car::new_with_rng(&mut StdRng::seed_from_u64(7)).get_parameter_vector();
+        let b = Car::new_with_rng(&mut StdRng::seed_from_u64(7)).get_parameter_vector();
+        let c = Car::new_with_rng(&mut StdRng::seed_from_u64(8)).get_parameter_vector();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn display() {
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let report = car.to_string();
+        assert!(report.contains(&car.motor.name));
+        assert!(report.contains(&car.rear_tire.id));
+        assert!(report.contains("Objectives"));
+        assert_eq!(
+            report
+                .lines()
+                .skip_while(|line| *line != "Objectives")
+                .count(),
+            12
+        );
+    }
+
+    #[test]
+    fn typed_objectives() {
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let objectives = car.objectives();
+        assert_eq!(objectives.mass, car.mass());
+        assert_eq!(objectives.corner_velocity, car.corner_velocity());
+
+        let vector = objectives.to_vector();
+        assert_eq!(vector[2], car.total_drag_force().get::<newton>());
+        assert_eq!(vector[10], car.pitch_moment().get::<newton_meter>());
+
+        let mut weights = [0.0; 11];
+        weights[3] = 1.0;
+        assert_eq!(car.objective(weights), -vector[3]);
+    }
+
+    #[test]
+    fn weight_scenarios() {
+        use argmin::prelude::ArgminOp;
+
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0))
+            .with_weights(WeightScenario::Scenario1);
+        let p = car.get_parameter_vector();
+        let expected = car.objective(WeightScenario::Scenario1);
+        assert_eq!(car.apply(&p).unwrap(), expected);
+        assert_eq!(car.objective(WeightScenario::Scenario1.weights()), expected);
+        assert_ne!(car.objective(WeightScenario::Even), expected);
+    }
+
+    #[test]
+    fn apply_rejects_wrong_parameter_count() {
+        use argmin::prelude::ArgminOp;
+
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let mut p = car.get_parameter_vector();
+        assert!(car.apply(&p).is_ok());
+        p.pop();
+        assert!(car.apply(&p).is_err());
+        assert!(car.apply(&vec![0.0; 3]).is_err());
+    }
+
+    #[test]
+    fn normalized_objective() {
+        let normalization = Normalization::builtin();
+        let car =
+            Car::new_with_rng(&mut StdRng::seed_from_u64(0)).with_normalization(normalization);
+        assert_eq!(car.normalization(), Some(&normalization));
+        let normalized = normalization.normalize(&car.objectives());
+        assert!(normalized.iter().all(|value| value.is_finite()));
+        assert_eq!(
+            car.objective(WeightScenario::Even),
+            normalized.iter().sum::<f64>()
+        );
+    }
+
+    #[test]
+    fn tire_grip() {
+        let grip = TireGrip {
+            load_sensitivity: 0.15,
+            pressure_sensitivity: 2.5,
+            ..TireGrip::default()
+        };
+        let nominal = grip.nominal_load;
+        assert_eq!(grip.friction(nominal, grip.optimal_pressure), 1.6);
+        assert!(grip.friction(2.0 * nominal, grip.optimal_pressure) < 1.6);
+        assert!(grip.friction(nominal, grip.optimal_pressure * 1.1) < 1.6);
+        assert_eq!(
+            TireGrip::default().friction(2.0 * nominal, Pressure::new::<bar>(0.8)),
+            1.6
+        );
+
+        // Inflating the tires to their optimal pressure gives the most grip
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let mut p = car.get_parameter_vector();
+        let schema = car.parameter_schema();
+        let rear = schema.index_of("rear_tire_pressure").unwrap();
+        let front = schema.index_of("front_tire_pressure").unwrap();
+        p[rear] = car.rear_tire.grip.optimal_pressure.get::<bar>();
+        p[front] = car.front_tire.grip.optimal_pressure.get::<bar>();
+        let optimal = Car::new_from_parameters(&p);
+        p[rear] = schema.get("rear_tire_pressure").unwrap().bounds.unwrap()[1];
+        p[front] = schema.get("front_tire_pressure").unwrap().bounds.unwrap()[1];
+        let overinflated = Car::new_from_parameters(&p);
+        assert!(overinflated.lateral_grip() < optimal.lateral_grip());
+        assert!(overinflated.rear_grip() < optimal.rear_grip());
+    }
+
+    #[test]
+    fn energy_objective() {
+        // This design finishes the endurance event
+        let normalization = Normalization::builtin();
+        let car =
+            Car::new_with_rng(&mut StdRng::seed_from_u64(2)).with_normalization(normalization);
+        assert_eq!(car.objectives().energy, None);
+
+        let efficient = car.clone().with_energy_objective(2.0);
+        let energy = efficient.objectives().energy.unwrap();
+        assert_eq!(energy, efficient.endurance_consumption());
+        let fuel = efficient.endurance_fuel().unwrap();
+        assert!((fuel.value * 43.4e6 - energy.value).abs() < 1e-6 * energy.value);
+        let expected = car.objective(WeightScenario::Even)
+            + 2.0 * normalization.normalize_energy(energy).unwrap();
+        assert!((efficient.objective(WeightScenario::Even) - expected).abs() < 1e-9);
+
+        // A car that stalls on a climb it cannot make never finishes the endurance event
+        let wall = Track::new(vec![Segment::Straight {
+            length: Length::new::<meter>(100.0),
+            elevation_change: Length::new::<meter>(90.0),
+        }]);
+        let stalled = efficient.with_competition(Competition {
+            endurance_lap: wall,
+            ..Competition::default()
+        });
+        assert!(stalled.objectives().energy.unwrap().value.is_infinite());
+        assert_eq!(stalled.objective(WeightScenario::Even), f64::INFINITY);
+        let ignored = stalled.with_energy_objective(0.0);
+        assert_eq!(
+            ignored.objective(WeightScenario::Even),
+            car.objective(WeightScenario::Even)
+        );
+    }
+
+    #[test]
+    fn operating_conditions() {
+        use uom::si::{f64::MassDensity, mass_density::kilogram_per_cubic_meter};
+
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        assert_eq!(*car.conditions(), OperatingConditions::default());
+        let larger_skidpad = car.clone().with_conditions(OperatingConditions {
+            skidpad_radius: Length::new::<meter>(18.0),
+            ..OperatingConditions::default()
+        });
+        let altitude = car.clone().with_conditions(OperatingConditions {
+            air_density: MassDensity::new::<kilogram_per_cubic_meter>(1.0),
+            ..OperatingConditions::default()
+        });
+        if car.corner_velocity().value > 0.0 {
+            assert!(larger_skidpad.corner_velocity() > car.corner_velocity());
+        }
+        assert!(altitude.total_drag_force() < car.total_drag_force());
+    }
+
+    #[test]
+    fn independent_suspension() {
+        assert_eq!(
+            Car::new_with_rng(&mut StdRng::seed_from_u64(0))
+                .get_parameter_vector()
+                .len(),
+            39
+        );
+
+        let variant = ProblemVariant {
+            independent_suspension: true,
+            ..ProblemVariant::ORIGINAL
+        };
+        let car = Car::new_with_variant_and_rng(
+            Catalog::builtin(),
+            variant,
+            &mut StdRng::seed_from_u64(0),
+        );
+        let p = car.get_parameter_vector();
+        assert_eq!(p.len(), variant.parameter_count());
+        let rebuilt = Car::new_from_parameters_with_variant(&p, Catalog::builtin(), variant);
+        assert_eq!(rebuilt.front_suspension, car.front_suspension);
+        assert_eq!(rebuilt.rear_suspension, car.rear_suspension);
+    }
+
+    #[test]
+    fn gearing() {
+        let variant = ProblemVariant {
+            variable_final_drive: true,
+            ..ProblemVariant::ORIGINAL
+        };
+        let car = Car::new_with_variant_and_rng(
+            Catalog::builtin(),
+            variant,
+            &mut StdRng::seed_from_u64(0),
+        );
+        let mut p = car.get_parameter_vector();
+        let index = car
+            .parameter_schema()
+            .index_of("final_drive_ratio")
+            .unwrap();
+        assert_eq!(p[index], car.final_drive_ratio());
+
+        // Away from the redline, a shorter final drive multiplies the force at the wheels
+        let slow = Velocity::new::<meter_per_second>(2.0);
+        p[index] = 1.4;
+        let long = Car::new_from_parameters_with_variant(&p, Catalog::builtin(), variant);
+        p[index] = 2.8;
+        let short = Car::new_from_parameters_with_variant(&p, Catalog::builtin(), variant);
+        let gain = short.at_speed(slow).tractive_force() / long.at_speed(slow).tractive_force();
+        assert!((gain.value - 2.0).abs() < 1e-9);
+
+        // Past the redline the engine gives nothing, and the shorter gearing reaches it sooner
+        let redline = short.motor.torque_curve.max_speed();
+        let beyond = Velocity::new::<meter_per_second>(
+            1.01 * (redline.value / 2.8) * short.rear_tire.radius.value,
+        );
+        assert_eq!(short.at_speed(beyond).tractive_force(), Force::default());
+        assert!(long.at_speed(beyond).tractive_force() > Force::default());
+        assert!(short.at_speed(beyond).engine_speed() > redline);
+    }
+
+    #[test]
+    fn final_drive() {
+        // The fixed final drive, which is also the shortest one allowed, keeps every catalog motor
+        // on every catalog tire below its redline and pulling at the operating speed
+        assert_eq!(
+            constants::FINAL_DRIVE_RATIO,
+            constants::FINAL_DRIVE_BOUNDS[1]
+        );
+        let mut car = Car::new_with_rng(&mut StdRng::seed_from_u64(1));
+        let catalog = Catalog::builtin();
+        for motor in catalog.motors() {
+            for tire in catalog.tires() {
+                car.motor = motor.clone();
+                car.rear_tire = tire.clone();
+                assert!(car.engine_speed() < car.motor.torque_curve.max_speed());
+                assert!(car.tractive_force() > Force::default());
+            }
+        }
+
+        // A design with a rear wing of ordinary span and the most powerful engine accelerates
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(1));
+        let schema = car.parameter_schema();
+        let mut p = car.get_parameter_vector();
+        p[schema.index_of("rear_wing_width").unwrap()] = 1.0;
+        p[schema.index_of("motor_index").unwrap()] = (catalog.motors().len() - 1) as f64;
+        let car = Car::new_from_parameters(&p);
+        assert!(car.bounds_report().is_feasible());
+        assert!(car.acceleration() > Acceleration::default());
+    }
 }
@@ -0,0 +1,357 @@
+//! This contains the longitudinal layout of the car, its axle loads and the transfer of load
+//! between tires under acceleration, braking and cornering
+
+use uom::si::{
+    f64::{Acceleration, Force, Length, Mass, Pressure},
+    length::meter,
+};
+
+use crate::{constants, Car, Tire};
+
+/// The number of fixed-point iterations used where the grip depends on the load transfer it causes
+const ITERATIONS: usize = 8;
+
+/// The normal force carried by the front and rear axles
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AxleLoads {
+    pub front: Force,
+    pub rear: Force,
+}
+
+impl AxleLoads {
+    pub fn total(&self) -> Force {
+        self.front + self.rear
+    }
+
+    /// The share of the total carried by the front axle
+    pub fn front_fraction(&self) -> f64 {
+        (self.front / self.total()).value
+    }
+
+    /// These loads with `transfer` moved from the front axle to the rear, neither axle being left
+    /// with less than nothing
+    fn shifted(&self, transfer: Force) -> AxleLoads {
+        AxleLoads::from_rear(self.total(), self.rear + transfer)
+    }
+
+    /// A total load with the given share on the rear axle, limited to between nothing and all of it
+    fn from_rear(total: Force, rear: Force) -> AxleLoads {
+        let rear = if rear < Force::default() {
+            Force::default()
+        } else if rear > total {
+            total
+        } else {
+            rear
+        };
+        AxleLoads {
+            front: total - rear,
+            rear,
+        }
+    }
+
+    /// A force split between the axles in proportion to these loads
+    fn shared(&self, force: Force) -> AxleLoads {
+        let front_fraction = self.front_fraction();
+        AxleLoads {
+            front: force * front_fraction,
+            rear: force * (1.0 - front_fraction),
+        }
+    }
+}
+
+impl Car {
+    /// The distance between the front and rear axles
+    pub fn wheelbase(&self) -> Length {
+        Length::new::<meter>(constants::WHEELBASE)
+    }
+
+    /// The distance between the left and right tires of an axle
+    pub fn track_width(&self) -> Length {
+        Length::new::<meter>(constants::TRACK_WIDTH)
+    }
+
+    /// The mass of each component and the longitudinal position of its center, measured rearward
+    /// from the front axle
+    ///
+    /// The cabin starts a fixed distance ahead of the front axle with the impact attenuator and
+    /// front wing ahead of it, the motor and inverter sit just ahead of the rear axle, the rear
+    /// wing behind it, and the side wings and battery pack between the axles.
+    pub fn longitudinal_layout(&self) -> Vec<(Mass, Length)> {
+        let wheelbase = self.wheelbase();
+        let zero = Length::default();
+        let cabin_front = Length::new::<meter>(constants::CABIN_OVERHANG);
+        let motor = wheelbase - self.motor.length / 2.0;
+        vec![
+            (
+                self.mass_front_wing(),
+                -(self.front_tire.radius + self.front_wing_length / 2.0),
+            ),
+            (
+                self.mass_impact_attenuator(),
+                -(cabin_front + self.impact_attenuator_length / 2.0),
+            ),
+            (self.mass_cabin(), self.cabin_length / 2.0 - cabin_front),
+            (2.0 * self.mass_side_wings(), wheelbase / 2.0),
+            (self.motor.mass + self.mass_inverter(), motor),
+            (
+                self.mass_rear_wing(),
+                wheelbase + self.rear_tire.radius + self.rear_wing_length / 2.0,
+            ),
+            (2.0 * self.front_tire.mass, zero),
+            (2.0 * self.rear_tire.mass, wheelbase),
+            (2.0 * self.mass_brake(), zero),
+            (2.0 * self.mass_brake(), wheelbase),
+            (2.0 * self.front_suspension.front_mass, zero),
+            (2.0 * self.rear_suspension.rear_mass, wheelbase),
+            (
+                self.pack_mass(),
+                Length::new::<meter>(constants::PACK_X_POSITION),
+            ),
+        ]
+    }
+
+    /// The longitudinal position of the center of gravity, measured rearward from the front axle
+    pub fn longitudinal_center_of_gravity(&self) -> Length {
+        let layout = self.longitudinal_layout();
+        let total = layout
+            .iter()
+            .fold(Mass::default(), |total, (mass, _)| total + *mass);
+        layout
+            .iter()
+            .fold(Length::default(), |sum, (mass, x)| sum + *mass * *x / total)
+    }
+
+    /// The center of gravity as its longitudinal position from the front axle and its height
+    pub fn center_of_gravity_position(&self) -> (Length, Length) {
+        (
+            self.longitudinal_center_of_gravity(),
+            self.center_of_gravity(),
+        )
+    }
+
+    /// The loads on the axles from the weight and the downforce at the operating speed, with
+    /// each wing's downforce acting at its center
+    ///
+    /// A design whose weight and downforce together act ahead of the front axle or behind the rear
+    /// one would tip about that axle, so neither axle is left with less than nothing: the whole
+    /// load goes on the axle it would tip about.
+    pub fn static_axle_loads(&self) -> AxleLoads {
+        let wheelbase = self.wheelbase();
+        let weight = self.mass() * self.conditions.gravity;
+        let front_wing = self.wing_down_force(
+            self.front_wing_width,
+            self.front_wing_height,
+            self.front_wing_length,
+            self.front_wing_angle_of_attack,
+        );
+        let rear_wing = self.wing_down_force(
+            self.rear_wing_width,
+            self.rear_wing_height,
+            self.rear_wing_length,
+            self.rear_wing_angle_of_attack,
+        );
+        let side_wings = 2.0
+            * self.wing_down_force(
+                self.side_wings_width,
+                self.side_wings_height,
+                self.side_wings_length,
+                self.side_wings_angle_of_attack,
+            );
+        let x_front_wing = -(self.front_tire.radius + self.front_wing_length / 2.0);
+        let x_rear_wing = wheelbase + self.rear_tire.radius + self.rear_wing_length / 2.0;
+
+        let rear = (weight * self.longitudinal_center_of_gravity()
+            + front_wing * x_front_wing
+            + rear_wing * x_rear_wing
+            + side_wings * wheelbase / 2.0)
+            / wheelbase;
+        AxleLoads::from_rear(weight + front_wing + rear_wing + side_wings, rear)
+    }
+
+    /// The axle loads while accelerating, with load moving to the rear axle under positive
+    /// acceleration and to the front axle under braking, neither axle being left with less than
+    /// nothing
+    pub fn axle_loads(&self, acceleration: Acceleration) -> AxleLoads {
+        self.static_axle_loads()
+            .shifted(self.longitudinal_load_transfer(acceleration))
+    }
+
+    /// The load moved from the front axle to the rear by a longitudinal acceleration
+    pub fn longitudinal_load_transfer(&self, acceleration: Acceleration) -> Force {
+        self.mass() * acceleration * self.center_of_gravity() / self.wheelbase()
+    }
+
+    /// The load moved from the inner to the outer tire of each axle by a lateral acceleration,
+    /// shared between the axles in proportion to their static loads
+    pub fn lateral_load_transfer(&self, lateral_acceleration: Acceleration) -> AxleLoads {
+        let transfer =
+            self.mass() * lateral_acceleration * self.center_of_gravity() / self.track_width();
+        self.static_axle_loads().shared(transfer)
+    }
+
+    /// The friction coefficient of the front tires at their static load and pressure
+    pub fn front_grip(&self) -> f64 {
+        let load = self.static_axle_loads().front / 2.0;
+        self.front_tire
+            .grip
+            .friction(load, self.front_tire_pressure)
+    }
+
+    /// The friction coefficient of the rear tires at their static load and pressure
+    pub fn rear_grip(&self) -> f64 {
+        let load = self.static_axle_loads().rear / 2.0;
+        self.rear_tire.grip.friction(load, self.rear_tire_pressure)
+    }
+
+    /// The lateral friction coefficient of the car as a whole, allowing for the lateral load
+    /// transfer at the cornering limit
+    pub(crate) fn lateral_grip(&self) -> f64 {
+        let loads = self.static_axle_loads();
+        let mass = self.mass();
+        let lever = self.center_of_gravity() / self.track_width();
+        let mut lateral_acceleration = Acceleration::default();
+        let mut grip = 0.0;
+        for _ in 0..ITERATIONS {
+            let transfer = loads.shared(mass * lateral_acceleration * lever);
+            let force = axle_grip(
+                &self.front_tire,
+                self.front_tire_pressure,
+                loads.front,
+                transfer.front,
+            ) + axle_grip(
+                &self.rear_tire,
+                self.rear_tire_pressure,
+                loads.rear,
+                transfer.rear,
+            );
+            grip = (force / loads.total()).value;
+            lateral_acceleration = force / mass;
+        }
+        grip
+    }
+
+    /// The largest tractive force that the rear tires can put down, allowing for the load moved
+    /// onto them by the acceleration
+    pub(crate) fn traction_limit(&self) -> Force {
+        let loads = self.static_axle_loads();
+        let lever = self.center_of_gravity() / self.wheelbase();
+        let mut force = Force::default();
+        for _ in 0..ITERATIONS {
+            let loads = loads.shifted(force * lever);
+            force = axle_grip(
+                &self.rear_tire,
+                self.rear_tire_pressure,
+                loads.rear,
+                Force::default(),
+            );
+        }
+        force
+    }
+
+    /// The largest deceleration that the tires can hold, allowing for the load moved onto the
+    /// front axle under braking
+    pub(crate) fn braking_grip_limit(&self) -> Acceleration {
+        let loads = self.static_axle_loads();
+        let mass = self.mass();
+        let lever = self.center_of_gravity() / self.wheelbase();
+        let mut deceleration = Acceleration::default();
+        for _ in 0..ITERATIONS {
+            let loads = loads.shifted(-(mass * deceleration * lever));
+            let force = axle_grip(
+                &self.front_tire,
+                self.front_tire_pressure,
+                loads.front,
+                Force::default(),
+            ) + axle_grip(
+                &self.rear_tire,
+                self.rear_tire_pressure,
+                loads.rear,
+                Force::default(),
+            );
+            deceleration = force / mass;
+        }
+        deceleration
+    }
+}
+
+/// The friction force of the two tires of an axle carrying `load` between them, with `transfer`
+/// moved from the inner tire to the outer one
+fn axle_grip(tire: &Tire, pressure: Pressure, load: Force, transfer: Force) -> Force {
+    let zero = Force::default();
+    if load <= zero {
+        return zero;
+    }
+    let half = load / 2.0;
+    let transfer = if transfer > half { half } else { transfer };
+    let (outer, inner) = (half + transfer, half - transfer);
+    let friction = |load: Force| {
+        if load > zero {
+            load * tire.grip.friction(load, pressure)
+        } else {
+            zero
+        }
+    };
+    friction(outer) + friction(inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use uom::si::acceleration::meter_per_second_squared;
+
+    #[test]
+    fn loads() {
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let layout_mass = car
+            .longitudinal_layout()
+            .iter()
+            .fold(Mass::default(), |total, (mass, _)| total + *mass);
+        assert!(((layout_mass - car.mass()) / car.mass()).value.abs() < 1e-12);
+
+        let loads = car.static_axle_loads();
+        let expected = car.mass() * car.conditions.gravity + car.total_downward_force();
+        assert!(((loads.total() - expected) / expected).value.abs() < 1e-12);
+
+        let braking = car.axle_loads(Acceleration::new::<meter_per_second_squared>(-5.0));
+        assert!(((braking.total() - loads.total()) / expected).value.abs() < 1e-12);
+        assert!(braking.front >= loads.front);
+
+        let cornering =
+            car.lateral_load_transfer(Acceleration::new::<meter_per_second_squared>(10.0));
+        assert!(cornering.front >= Force::default());
+        assert!(car.traction_limit() >= Force::default());
+        assert!(car.braking_grip_limit() >= Acceleration::default());
+
+        // Designs whose center of gravity lies outside the wheelbase still load both axles
+        // non-negatively
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut outside = 0;
+        for _ in 0..300 {
+            let car = Car::new_with_rng(&mut rng);
+            let x = car.longitudinal_center_of_gravity();
+            if x < Length::default() || x > car.wheelbase() {
+                outside += 1;
+            }
+            let loads = car.static_axle_loads();
+            assert!(loads.front >= Force::default() && loads.rear >= Force::default());
+        }
+        assert!(outside > 0);
+    }
+
+    #[test]
+    fn load_sensitivity() {
+        // Moving load across an axle of load-sensitive tires loses grip
+        let mut tire = Tire::default();
+        tire.grip.load_sensitivity = 0.15;
+        let pressure = tire.grip.optimal_pressure;
+        let load = 4.0 * tire.grip.nominal_load;
+        let even = axle_grip(&tire, pressure, load, Force::default());
+        let shifted = axle_grip(&tire, pressure, load, load / 4.0);
+        assert!(shifted < even);
+        assert_eq!(
+            axle_grip(&tire, pressure, -load, Force::default()),
+            Force::default()
+        );
+    }
+}
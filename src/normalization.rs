@@ -0,0 +1,200 @@
+//! This contains the scaling of the objectives between ideal and nadir reference points
+
+use std::sync::Arc;
+
+use rand::Rng;
+use uom::si::{energy::joule, f64::Energy};
+
+use crate::{Car, Catalog, Objectives, ProblemVariant};
+
+/// Reference points used to scale each objective to roughly [0, 1]
+///
+/// Both points are in the direction-corrected form of [`Objectives::to_minimization_vector`],
+/// so `ideal` holds the best value seen for each objective and `nadir` the worst.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normalization {
+    pub ideal: [f64; 11],
+    pub nadir: [f64; 11],
+    /// The ideal and nadir energy consumption in J, if known
+    pub energy: Option<[f64; 2]>,
+}
+
+impl Normalization {
+    /// Reference points estimated from 10,000 random designs of the built-in catalog, and the
+    /// energy range from the designs among the first 1,000 that finish the endurance event, all
+    /// drawn from a generator seeded with 0
+    ///
+    /// The `builtin` test regenerates this table and checks that it is current:
+    /// `cargo test --release builtin -- --ignored --nocapture`.
+    pub fn builtin() -> Self {
+        Normalization {
+            ideal: [
+                123.006, 0.158349, 6.58647, -8917.38, -1.9095, 1.68632e6, 0.00433591, -13.8208,
+                15.4226, 9.8107, 1.34832,
+            ],
+            nadir: [
+                9284.28, 1.00984, 660.453, -0.623041, 0.655363, 1.15652e8, 0.135817, 0.0, 781.095,
+                24.0748, 23538.5,
+            ],
+            energy: Some([1.18784e7, 4.64572e7]),
+        }
+    }
+
+    /// Estimate the reference points from the objectives of randomly generated cars
+    ///
+    /// Objectives that are not finite are skipped. The energy objective is not evaluated, so the
+    /// estimate has no energy range.
+    pub fn estimate<R: Rng + ?Sized>(
+        catalog: impl Into<Arc<Catalog>>,
+        variant: ProblemVariant,
+        samples: usize,
+        rng: &mut R,
+    ) -> Self {
+        let catalog = catalog.into();
+        Normalization::from_objectives(
+            (0..samples)
+                .map(|_| Car::new_with_variant_and_rng(catalog.clone(), variant, rng).objectives()),
+        )
+    }
+
+    /// The ideal and nadir points of a set of evaluated designs
+    pub fn from_objectives(objectives: impl IntoIterator<Item = Objectives>) -> Self {
+        let mut normalization = Normalization {
+            ideal: [f64::INFINITY; 11],
+            nadir: [f64::NEG_INFINITY; 11],
+            energy: None,
+        };
+        for objectives in objectives {
+            if let Some(energy) = objectives.energy.map(|energy| energy.get::<joule>()) {
+                if energy.is_finite() {
+                    let [ideal, nadir] = normalization
+                        .energy
+                        .unwrap_or([f64::INFINITY, f64::NEG_INFINITY]);
+                    normalization.energy = Some([ideal.min(energy), nadir.max(energy)]);
+                }
+            }
+            let vector = objectives.to_minimization_vector();
+            for (i, value) in vector.iter().enumerate() {
+                if value.is_finite() {
+                    normalization.ideal[i] = normalization.ideal[i].min(*value);
+                    normalization.nadir[i] = normalization.nadir[i].max(*value);
+                }
+            }
+        }
+        normalization
+    }
+
+    /// The direction-corrected objectives scaled so that the ideal point maps to 0 and the nadir
+    /// point to 1
+    pub fn normalize(&self, objectives: &Objectives) -> [f64; 11] {
+        let mut vector = objectives.to_minimization_vector();
+        for (i, value) in vector.iter_mut().enumerate() {
+            let range = self.nadir[i] - self.ideal[i];
+            *value = if range > 0.0 {
+                (*value - self.ideal[i]) / range
+            } else {
+                0.0
+            };
+        }
+        vector
+    }
+
+    /// The energy consumed scaled like [`Normalization::normalize`], or `None` without an energy
+    /// range
+    pub fn normalize_energy(&self, energy: Energy) -> Option<f64> {
+        let [ideal, nadir] = self.energy?;
+        let range = nadir - ideal;
+        Some(if range > 0.0 {
+            (energy.get::<joule>() - ideal) / range
+        } else {
+            0.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn estimate() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let normalization =
+            Normalization::estimate(Catalog::builtin(), ProblemVariant::ORIGINAL, 100, &mut rng);
+        for i in 0..Objectives::COUNT {
+            assert!(normalization.ideal[i] <= normalization.nadir[i]);
+        }
+
+        let car = Car::new_with_rng(&mut rng);
+        let normalized =
+            Normalization::from_objectives(vec![car.objectives()]).normalize(&car.objectives());
+        assert_eq!(normalized, [0.0; 11]);
+        assert_eq!(
+            Normalization::from_objectives(vec![car.objectives()]).energy,
+            None
+        );
+
+        let energy = |joules| Objectives {
+            energy: Some(Energy::new::<joule>(joules)),
+            ..car.objectives()
+        };
+        let normalization = Normalization::from_objectives(vec![energy(1e6), energy(3e6)]);
+        assert_eq!(normalization.energy, Some([1e6, 3e6]));
+        assert_eq!(
+            normalization.normalize_energy(Energy::new::<joule>(2e6)),
+            Some(0.5)
+        );
+    }
+
+    #[test]
+    #[ignore = "evaluates 10,000 designs; run in release mode"]
+    fn builtin() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut normalization = Normalization::estimate(
+            Catalog::builtin(),
+            ProblemVariant::ORIGINAL,
+            10_000,
+            &mut rng,
+        );
+        let mut rng = StdRng::seed_from_u64(0);
+        normalization.energy = Normalization::from_objectives((0..1_000).map(|_| {
+            Car::new_with_rng(&mut rng)
+                .with_energy_objective(1.0)
+                .objectives()
+        }))
+        .energy;
+
+        let list = |values: &[f64]| {
+            values
+                .iter()
+                .map(|value| {
+                    // six significant figures, written so that the output pastes as f64 literals
+                    let rounded: f64 = format!("{:.5e}", value).parse().unwrap();
+                    if rounded.abs() >= 1e6 {
+                        format!("{:e}", rounded)
+                    } else {
+                        format!("{:?}", rounded + 0.0)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        println!("ideal: [{}],", list(&normalization.ideal));
+        println!("nadir: [{}],", list(&normalization.nadir));
+        println!("energy: Some([{}]),", list(&normalization.energy.unwrap()));
+
+        let close = |a: &[f64], b: &[f64]| {
+            a.iter()
+                .zip(b)
+                .all(|(a, b)| (a - b).abs() <= 1e-5 * a.abs().max(b.abs()))
+        };
+        let builtin = Normalization::builtin();
+        assert!(close(&builtin.ideal, &normalization.ideal));
+        assert!(close(&builtin.nadir, &normalization.nadir));
+        assert!(close(
+            &builtin.energy.unwrap(),
+            &normalization.energy.unwrap()
+        ));
+    }
+}
@@ -0,0 +1,150 @@
+//! This contains the objectives of the design problem and their optimization directions
+
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    energy::joule,
+    f64::{Acceleration, Energy, Force, Length, Mass, Torque, Velocity, Volume},
+    force::newton,
+    length::meter,
+    mass::kilogram,
+    torque::newton_meter,
+    velocity::meter_per_second,
+    volume::cubic_meter,
+};
+
+/// Whether an objective should be made as small or as large as possible
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sense {
+    Minimize,
+    Maximize,
+}
+
+/// The 11 objectives evaluated for a particular car, and the optional twelfth
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Objectives {
+    pub mass: Mass,
+    /// Height of the center of gravity
+    pub center_of_gravity: Length,
+    pub drag_force: Force,
+    pub downforce: Force,
+    pub acceleration: Acceleration,
+    pub crash_force: Force,
+    pub impact_attenuator_volume: Volume,
+    /// Velocity around the skidpad
+    pub corner_velocity: Velocity,
+    pub braking_distance: Length,
+    pub suspension_acceleration: Acceleration,
+    pub pitch_moment: Torque,
+    /// Energy consumed over the endurance event, only evaluated when enabled with
+    /// `Car::with_energy_objective`
+    pub energy: Option<Energy>,
+}
+
+impl Objectives {
+    /// The number of objectives
+    pub const COUNT: usize = 11;
+
+    /// The name of each objective, in vector order
+    pub const NAMES: [&'static str; 11] = [
+        "mass",
+        "center_of_gravity",
+        "drag_force",
+        "downforce",
+        "acceleration",
+        "crash_force",
+        "impact_attenuator_volume",
+        "corner_velocity",
+        "braking_distance",
+        "suspension_acceleration",
+        "pitch_moment",
+    ];
+
+    /// The unit of each entry of [`Objectives::to_vector`]
+    pub const UNITS: [&'static str; 11] = [
+        "kg", "m", "N", "N", "m/s^2", "N", "m^3", "m/s", "m", "m/s^2", "N m",
+    ];
+
+    /// The optimization direction of each objective, in vector order
+    pub const SENSES: [Sense; 11] = [
+        Sense::Minimize,
+        Sense::Minimize,
+        Sense::Minimize,
+        Sense::Maximize,
+        Sense::Maximize,
+        Sense::Minimize,
+        Sense::Minimize,
+        Sense::Maximize,
+        Sense::Minimize,
+        Sense::Minimize,
+        Sense::Minimize,
+    ];
+
+    /// The name of the optional twelfth objective, which is to be minimized
+    pub const ENERGY_NAME: &'static str = "energy";
+
+    /// The unit of the optional twelfth objective in [`Objectives::to_full_vector`]
+    pub const ENERGY_UNIT: &'static str = "J";
+
+    /// The objectives as plain numbers in SI base units
+    pub fn to_vector(&self) -> [f64; 11] {
+        [
+            self.mass.get::<kilogram>(),
+            self.center_of_gravity.get::<meter>(),
+            self.drag_force.get::<newton>(),
+            self.downforce.get::<newton>(),
+            self.acceleration.get::<meter_per_second_squared>(),
+            self.crash_force.get::<newton>(),
+            self.impact_attenuator_volume.get::<cubic_meter>(),
+            self.corner_velocity.get::<meter_per_second>(),
+            self.braking_distance.get::<meter>(),
+            self.suspension_acceleration
+                .get::<meter_per_second_squared>(),
+            self.pitch_moment.get::<newton_meter>(),
+        ]
+    }
+
+    /// The objectives as in [`Objectives::to_vector`], followed by the energy if it was evaluated
+    pub fn to_full_vector(&self) -> Vec<f64> {
+        let mut vector = self.to_vector().to_vec();
+        vector.extend(self.energy.map(|energy| energy.get::<joule>()));
+        vector
+    }
+
+    /// The objectives as in [`Objectives::to_vector`], with the sign of every objective that is
+    /// to be maximized flipped so that all of them are to be minimized
+    pub fn to_minimization_vector(&self) -> [f64; 11] {
+        let mut vector = self.to_vector();
+        for (value, sense) in vector.iter_mut().zip(Objectives::SENSES.iter()) {
+            if *sense == Sense::Maximize {
+                *value = -*value;
+            }
+        }
+        vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimization_vector() {
+        let objectives = Objectives {
+            mass: Mass::new::<kilogram>(250.0),
+            downforce: Force::new::<newton>(800.0),
+            corner_velocity: Velocity::new::<meter_per_second>(12.0),
+            ..Objectives::default()
+        };
+        let vector = objectives.to_minimization_vector();
+        assert_eq!(vector[0], 250.0);
+        assert_eq!(vector[3], -800.0);
+        assert_eq!(vector[7], -12.0);
+        assert_eq!(objectives.to_full_vector().len(), Objectives::COUNT);
+
+        let objectives = Objectives {
+            energy: Some(Energy::new::<joule>(1.5e7)),
+            ..objectives
+        };
+        assert_eq!(objectives.to_full_vector()[Objectives::COUNT], 1.5e7);
+    }
+}
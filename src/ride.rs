@@ -0,0 +1,491 @@
+//! This contains a time-domain simulation of the suspension over a road profile, using
+//! quarter-car and pitch-plane half-car models
+
+use std::f64::consts::PI;
+
+use rand::Rng;
+use uom::si::{
+    acceleration::meter_per_second_squared,
+    angular_acceleration::radian_per_second_squared,
+    f64::{Acceleration, AngularAcceleration, Length, Mass, Time, Velocity},
+    length::meter,
+    mass::kilogram,
+    time::second,
+    velocity::meter_per_second,
+};
+
+use crate::Car;
+
+/// The integration time step, short enough to resolve the wheel hop of the stiffest tires
+const TIME_STEP: f64 = 2.5e-4;
+
+/// The number of sinusoids summed to make a random road
+const ROAD_COMPONENTS: usize = 200;
+
+/// The range of spatial frequencies of a random road, in cycles/m, as given by ISO 8608
+const ROAD_FREQUENCIES: [f64; 2] = [0.011, 2.83];
+
+/// The reference spatial frequency of ISO 8608, in cycles/m
+const REFERENCE_FREQUENCY: f64 = 0.1;
+
+/// The smallest share of the sprung mass given to either axle, so that a design with its center
+/// of gravity outside the wheelbase still has a body on each axle. The sprung center of gravity
+/// is kept this share of the wheelbase clear of each axle.
+const MIN_AXLE_SHARE: f64 = 0.05;
+
+/// The road roughness classes of ISO 8608, from a smooth highway (A) to a very rough track (H)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoadClass {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+}
+
+impl RoadClass {
+    /// The displacement power spectral density at the reference spatial frequency of 0.1
+    /// cycles/m, in m³, taken as the geometric mean of the class
+    pub fn roughness(&self) -> f64 {
+        let class = match self {
+            RoadClass::A => 0,
+            RoadClass::B => 1,
+            RoadClass::C => 2,
+            RoadClass::D => 3,
+            RoadClass::E => 4,
+            RoadClass::F => 5,
+            RoadClass::G => 6,
+            RoadClass::H => 7,
+        };
+        16e-6 * 4f64.powi(class)
+    }
+}
+
+/// A random road built as a sum of sinusoids with random phases
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomRoad {
+    /// The amplitude in m, spatial frequency in cycles/m and phase in rad of each sinusoid
+    components: Vec<(f64, f64, f64)>,
+}
+
+impl RandomRoad {
+    /// A road with the spectral density `roughness * (n / 0.1)^-2` of ISO 8608, with spatial
+    /// frequencies `n` spaced logarithmically across the range of the standard
+    pub fn iso8608<R: Rng + ?Sized>(class: RoadClass, rng: &mut R) -> Self {
+        let [low, high] = ROAD_FREQUENCIES;
+        let spacing = (high / low).ln() / ROAD_COMPONENTS as f64;
+        let components = (0..ROAD_COMPONENTS)
+            .map(|i| {
+                let lower = low * (spacing * i as f64).exp();
+                let upper = low * (spacing * (i + 1) as f64).exp();
+                let frequency = (lower * upper).sqrt();
+                let density = class.roughness() * (frequency / REFERENCE_FREQUENCY).powi(-2);
+                let amplitude = (2.0 * density * (upper - lower)).sqrt();
+                (amplitude, frequency, rng.gen_range(0.0..2.0 * PI))
+            })
+            .collect();
+        RandomRoad { components }
+    }
+
+    fn elevation(&self, x: f64) -> f64 {
+        // Each sinusoid is offset to start at zero, so that the car starts on level ground
+        self.components
+            .iter()
+            .map(|(amplitude, frequency, phase)| {
+                amplitude * ((2.0 * PI * frequency * x + phase).cos() - phase.cos())
+            })
+            .sum()
+    }
+}
+
+/// The elevation of the road along its length, level before its start
+#[derive(Clone, Debug, PartialEq)]
+pub enum RoadProfile {
+    /// A single cosine-shaped bump at the start of the road
+    Bump { height: Length, length: Length },
+    /// A sinusoidal road starting at zero elevation
+    Sine {
+        amplitude: Length,
+        wavelength: Length,
+    },
+    /// A random road, typically from [`RandomRoad::iso8608`]
+    Random(RandomRoad),
+}
+
+impl RoadProfile {
+    /// The elevation of the road at a distance from its start
+    pub fn elevation(&self, distance: Length) -> Length {
+        let x = distance.get::<meter>();
+        if x <= 0.0 {
+            return Length::default();
+        }
+        Length::new::<meter>(match self {
+            RoadProfile::Bump { height, length } => {
+                let length = length.get::<meter>();
+                if x < length {
+                    0.5 * height.get::<meter>() * (1.0 - (2.0 * PI * x / length).cos())
+                } else {
+                    0.0
+                }
+            }
+            RoadProfile::Sine {
+                amplitude,
+                wavelength,
+            } => amplitude.get::<meter>() * (2.0 * PI * x / wavelength.get::<meter>()).sin(),
+            RoadProfile::Random(road) => road.elevation(x),
+        })
+    }
+}
+
+/// One end of the car
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axle {
+    Front,
+    Rear,
+}
+
+/// The ride quality and road holding over a road profile
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RideResult {
+    /// The RMS vertical acceleration of the body, at its center of gravity for the half-car
+    pub body_acceleration: Acceleration,
+    /// The RMS pitch acceleration of the body, zero for the quarter-car
+    pub pitch_acceleration: AngularAcceleration,
+    /// The RMS dynamic tire load divided by the static tire load, for the worse axle
+    pub tire_load_variation: f64,
+}
+
+/// The properties of one corner of the car, per axle for the half-car
+#[derive(Clone, Copy, Debug)]
+struct Corner {
+    unsprung_mass: f64,
+    spring: f64,
+    damper: f64,
+    tire: f64,
+    /// The static tire load, which the tire cannot pull back against when it leaves the road
+    load: f64,
+}
+
+impl Corner {
+    // force on the body from the suspension, and on the wheel from the tire
+    fn forces(&self, deflection: f64, deflection_rate: f64, road: f64, wheel: f64) -> (f64, f64) {
+        let suspension = -self.spring * deflection - self.damper * deflection_rate;
+        let tire = self.tire * (road - wheel);
+        (
+            suspension,
+            if tire < -self.load { -self.load } else { tire },
+        )
+    }
+}
+
+impl Car {
+    /// The mass that moves with the wheels at one corner: the tire, brake and suspension
+    pub fn unsprung_mass(&self, axle: Axle) -> Mass {
+        match axle {
+            Axle::Front => {
+                self.front_tire.mass + self.mass_brake() + self.front_suspension.front_mass
+            }
+            Axle::Rear => self.rear_tire.mass + self.mass_brake() + self.rear_suspension.rear_mass,
+        }
+    }
+
+    /// The mass carried on the springs
+    pub fn sprung_mass(&self) -> Mass {
+        self.mass() - 2.0 * self.unsprung_mass(Axle::Front) - 2.0 * self.unsprung_mass(Axle::Rear)
+    }
+
+    /// Simulate one corner of the car driving over a road at a constant speed
+    ///
+    /// The corner carries its axle's share of the sprung mass, split by the longitudinal position
+    /// of the sprung center of gravity. The front wheels are at the start of the road at time zero.
+    pub fn quarter_car(
+        &self,
+        axle: Axle,
+        road: &RoadProfile,
+        speed: Velocity,
+        duration: Time,
+    ) -> RideResult {
+        let (x_cg, _) = self.sprung_layout();
+        let front_share = 1.0 - x_cg / self.wheelbase().get::<meter>();
+        let share = match axle {
+            Axle::Front => front_share,
+            Axle::Rear => 1.0 - front_share,
+        };
+        let body = 0.5 * share * self.sprung_mass().get::<kilogram>();
+        let corner = self.corner(axle, body, 1.0);
+        let delay = match axle {
+            Axle::Front => 0.0,
+            Axle::Rear => self.wheelbase().get::<meter>(),
+        };
+        let speed = speed.get::<meter_per_second>();
+
+        // state: body and wheel displacements from equilibrium, then their velocities
+        let derivative = |t: f64, y: &[f64; 4]| {
+            let elevation = road.elevation(Length::new::<meter>(speed * t - delay));
+            let (suspension, tire) =
+                corner.forces(y[0] - y[1], y[2] - y[3], elevation.get::<meter>(), y[1]);
+            [
+                y[2],
+                y[3],
+                suspension / body,
+                (tire - suspension) / corner.unsprung_mass,
+            ]
+        };
+        let mut statistics = Statistics::default();
+        integrate([0.0; 4], duration, derivative, |t, y, dy| {
+            let elevation = road.elevation(Length::new::<meter>(speed * t - delay));
+            let (_, tire) = corner.forces(y[0] - y[1], y[2] - y[3], elevation.get::<meter>(), y[1]);
+            statistics.add(dy[2], 0.0, [tire / corner.load, 0.0]);
+        });
+        statistics.result()
+    }
+
+    /// Simulate the car driving over a road at a constant speed, with the body free to heave and
+    /// pitch and the rear wheels following the front ones a wheelbase behind
+    ///
+    /// The pitch inertia treats each component as a point mass at its position in
+    /// [`Car::longitudinal_layout`]. The front wheels are at the start of the road at time zero.
+    pub fn half_car(&self, road: &RoadProfile, speed: Velocity, duration: Time) -> RideResult {
+        let wheelbase = self.wheelbase().get::<meter>();
+        let (x_cg, inertia) = self.sprung_layout();
+        let body = self.sprung_mass().get::<kilogram>();
+        let front_share = 1.0 - x_cg / wheelbase;
+        let front = self.corner(Axle::Front, body * front_share, 2.0);
+        let rear = self.corner(Axle::Rear, body * (1.0 - front_share), 2.0);
+        // distances from the center of gravity forward to the front axle and back to the rear
+        let (a, b) = (x_cg, wheelbase - x_cg);
+        let speed = speed.get::<meter_per_second>();
+
+        // forces on the body from the front and rear suspension, and on the wheels from the tires
+        let forces = |t: f64, y: &[f64; 8]| {
+            let x = speed * t;
+            let road_front = road.elevation(Length::new::<meter>(x)).get::<meter>();
+            let road_rear = road
+                .elevation(Length::new::<meter>(x - wheelbase))
+                .get::<meter>();
+            let (front_suspension, front_tire) = front.forces(
+                y[0] + a * y[1] - y[2],
+                y[4] + a * y[5] - y[6],
+                road_front,
+                y[2],
+            );
+            let (rear_suspension, rear_tire) = rear.forces(
+                y[0] - b * y[1] - y[3],
+                y[4] - b * y[5] - y[7],
+                road_rear,
+                y[3],
+            );
+            (front_suspension, rear_suspension, front_tire, rear_tire)
+        };
+
+        // state: heave, pitch (nose up), front and rear wheel displacements, then their rates
+        let derivative = |t: f64, y: &[f64; 8]| {
+            let (front_suspension, rear_suspension, front_tire, rear_tire) = forces(t, y);
+            [
+                y[4],
+                y[5],
+                y[6],
+                y[7],
+                (front_suspension + rear_suspension) / body,
+                (a * front_suspension - b * rear_suspension) / inertia,
+                (front_tire - front_suspension) / front.unsprung_mass,
+                (rear_tire - rear_suspension) / rear.unsprung_mass,
+            ]
+        };
+        let mut statistics = Statistics::default();
+        integrate([0.0; 8], duration, derivative, |t, y, dy| {
+            let (_, _, front_tire, rear_tire) = forces(t, y);
+            statistics.add(
+                dy[4],
+                dy[5],
+                [front_tire / front.load, rear_tire / rear.load],
+            );
+        });
+        statistics.result()
+    }
+
+    /// The longitudinal position of the center of gravity of the sprung mass behind the front
+    /// axle in m, and its pitch inertia about that point in kg m²
+    ///
+    /// The position is kept within the wheelbase as described for [`MIN_AXLE_SHARE`], so that the
+    /// axle shares of the sprung mass and the lever arms of the pitch motion agree.
+    fn sprung_layout(&self) -> (f64, f64) {
+        let wheelbase = self.wheelbase().get::<meter>();
+        let unsprung = [
+            (2.0 * self.unsprung_mass(Axle::Front).get::<kilogram>(), 0.0),
+            (
+                2.0 * self.unsprung_mass(Axle::Rear).get::<kilogram>(),
+                wheelbase,
+            ),
+        ];
+        let layout: Vec<(f64, f64)> = self
+            .longitudinal_layout()
+            .iter()
+            .map(|(mass, x)| (mass.get::<kilogram>(), x.get::<meter>()))
+            .collect();
+        let moment = |power: i32, about: f64| {
+            let total: f64 = layout
+                .iter()
+                .map(|(mass, x)| mass * (x - about).powi(power))
+                .sum();
+            total
+                - unsprung
+                    .iter()
+                    .map(|(mass, x)| mass * (x - about).powi(power))
+                    .sum::<f64>()
+        };
+        let x_cg = (moment(1, 0.0) / moment(0, 0.0)).clamp(
+            MIN_AXLE_SHARE * wheelbase,
+            (1.0 - MIN_AXLE_SHARE) * wheelbase,
+        );
+        (x_cg, moment(2, x_cg))
+    }
+
+    /// One corner of an axle carrying the given sprung mass, or the whole axle if `corners` is 2
+    fn corner(&self, axle: Axle, body: f64, corners: f64) -> Corner {
+        let (tire, spring, damper) = match axle {
+            Axle::Front => (
+                &self.front_tire,
+                self.front_suspension.front_spring_constant,
+                self.front_suspension.front_damping_coefficient,
+            ),
+            Axle::Rear => (
+                &self.rear_tire,
+                self.rear_suspension.rear_spring_constant,
+                self.rear_suspension.rear_damping_coefficient,
+            ),
+        };
+        let unsprung_mass = corners * self.unsprung_mass(axle).get::<kilogram>();
+        let gravity = self.conditions.gravity.get::<meter_per_second_squared>();
+        Corner {
+            unsprung_mass,
+            spring: corners * spring.value,
+            damper: corners * damper.value,
+            tire: corners * tire.stiffness.value,
+            load: (body + unsprung_mass) * gravity,
+        }
+    }
+}
+
+/// Running sums of the squared body accelerations and tire load variations
+#[derive(Default)]
+struct Statistics {
+    samples: usize,
+    heave: f64,
+    pitch: f64,
+    loads: [f64; 2],
+}
+
+impl Statistics {
+    fn add(&mut self, heave: f64, pitch: f64, loads: [f64; 2]) {
+        self.samples += 1;
+        self.heave += heave * heave;
+        self.pitch += pitch * pitch;
+        for (sum, load) in self.loads.iter_mut().zip(loads.iter()) {
+            *sum += load * load;
+        }
+    }
+
+    fn result(&self) -> RideResult {
+        let rms = |sum: f64| (sum / self.samples.max(1) as f64).sqrt();
+        RideResult {
+            body_acceleration: Acceleration::new::<meter_per_second_squared>(rms(self.heave)),
+            pitch_acceleration: AngularAcceleration::new::<radian_per_second_squared>(rms(
+                self.pitch
+            )),
+            tire_load_variation: rms(self.loads[0]).max(rms(self.loads[1])),
+        }
+    }
+}
+
+/// Integrate a system of ODEs with the classical fourth-order Runge-Kutta method, passing the
+/// time, state and derivative to `record` after every step
+fn integrate<const N: usize>(
+    mut y: [f64; N],
+    duration: Time,
+    derivative: impl Fn(f64, &[f64; N]) -> [f64; N],
+    mut record: impl FnMut(f64, &[f64; N], &[f64; N]),
+) {
+    let h = TIME_STEP;
+    let steps = (duration.get::<second>() / h).ceil() as usize;
+    let offset = |y: &[f64; N], k: &[f64; N], scale: f64| {
+        let mut result = *y;
+        for (value, slope) in result.iter_mut().zip(k.iter()) {
+            *value += scale * slope;
+        }
+        result
+    };
+    for step in 0..steps {
+        let t = step as f64 * h;
+        let k1 = derivative(t, &y);
+        let k2 = derivative(t + h / 2.0, &offset(&y, &k1, h / 2.0));
+        let k3 = derivative(t + h / 2.0, &offset(&y, &k2, h / 2.0));
+        let k4 = derivative(t + h, &offset(&y, &k3, h));
+        for i in 0..N {
+            y[i] += h / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+        }
+        let t = t + h;
+        record(t, &y, &derivative(t, &y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn ride() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let car = Car::new_with_rng(&mut rng);
+        let speed = Velocity::new::<meter_per_second>(10.0);
+        let duration = Time::new::<second>(2.0);
+
+        let level = RoadProfile::Bump {
+            height: Length::default(),
+            length: Length::new::<meter>(1.0),
+        };
+        assert_eq!(car.half_car(&level, speed, duration), RideResult::default());
+
+        // Without lift-off the response is linear in the road roughness
+        let mut rng = StdRng::seed_from_u64(0);
+        let smooth = RoadProfile::Random(RandomRoad::iso8608(RoadClass::A, &mut rng));
+        let mut rng = StdRng::seed_from_u64(0);
+        let rough = RoadProfile::Random(RandomRoad::iso8608(RoadClass::B, &mut rng));
+        let smooth = car.half_car(&smooth, speed, duration);
+        let rough = car.half_car(&rough, speed, duration);
+        let ratio = rough.body_acceleration / smooth.body_acceleration;
+        assert!((ratio.value - 2.0).abs() < 1e-6);
+        assert!(smooth.pitch_acceleration > AngularAcceleration::default());
+        assert!(rough.tire_load_variation > smooth.tire_load_variation);
+
+        let bump = RoadProfile::Bump {
+            height: Length::new::<meter>(0.02),
+            length: Length::new::<meter>(0.5),
+        };
+        let swell = RoadProfile::Sine {
+            amplitude: Length::new::<meter>(0.02),
+            wavelength: Length::new::<meter>(100.0),
+        };
+        // A long swell is followed quasi-statically, while a short bump of the same height makes
+        // the tire load vary
+        let bump = car.quarter_car(Axle::Front, &bump, speed, duration);
+        let swell = car.quarter_car(Axle::Front, &swell, speed, duration);
+        assert!(swell.tire_load_variation < 0.01);
+        assert!(bump.tire_load_variation > 10.0 * swell.tire_load_variation);
+        assert!(bump.body_acceleration > swell.body_acceleration);
+
+        // The body sits between the axles even for designs whose center of gravity does not
+        let wheelbase = car.wheelbase().get::<meter>();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let (x_cg, inertia) = Car::new_with_rng(&mut rng).sprung_layout();
+            assert!(x_cg >= MIN_AXLE_SHARE * wheelbase);
+            assert!(x_cg <= (1.0 - MIN_AXLE_SHARE) * wheelbase);
+            assert!(inertia > 0.0);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! This contains the layout of the parameter vector
+
+use crate::{constants, Catalog, Powertrain, ProblemVariant};
+
+/// Whether a design variable takes real values, whole-number counts or indexes into a catalog
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterKind {
+    Continuous,
+    Integer,
+    Categorical,
+}
+
+/// One entry of the parameter vector
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameter {
+    pub name: &'static str,
+    /// Position in the parameter vector
+    pub index: usize,
+    /// The unit the value is expressed in, empty for catalog indices
+    pub unit: &'static str,
+    pub kind: ParameterKind,
+    /// The fixed bounds, or `None` when they depend on other variables (see
+    /// `Car::linear_constraints` and `Car::nonlinear_constraints`)
+    pub bounds: Option<[f64; 2]>,
+}
+
+/// The name, unit, kind and bounds of every entry of the parameter vector
+///
+/// This is the layout shared by `Car::get_parameter_vector` and `Car::new_from_parameters`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterSchema {
+    parameters: Vec<Parameter>,
+}
+
+impl ParameterSchema {
+    /// The layout for the given variant, with catalog indices bounded by the catalog sizes
+    pub fn new(catalog: &Catalog, variant: ProblemVariant) -> Self {
+        use ParameterKind::{Categorical, Continuous, Integer};
+
+        let constant = |i: usize| Some(constants::CONST_BOUNDS[i]);
+        let index = |length: usize| Some([0.0, length.saturating_sub(1) as f64]);
+        let materials = catalog.materials().len();
+        let suspensions = catalog.suspensions().len();
+        let electric = variant.powertrain == Powertrain::Electric;
+        let motors = if electric {
+            catalog.electric_motors().len()
+        } else {
+            catalog.motors().len()
+        };
+
+        let mut entries = vec![
+            ("rear_wing_height", "m", Continuous, constant(0)),
+            ("rear_wing_length", "m", Continuous, constant(1)),
+            ("rear_wing_angle_of_attack", "rad", Continuous, constant(2)),
+            ("front_wing_height", "m", Continuous, constant(3)),
+            ("front_wing_length", "m", Continuous, constant(4)),
+            ("front_wing_width", "m", Continuous, constant(5)),
+            ("front_wing_angle_of_attack", "rad", Continuous, constant(6)),
+            ("side_wings_height", "m", Continuous, constant(7)),
+            ("side_wings_length", "m", Continuous, constant(8)),
+            ("side_wings_width", "m", Continuous, constant(9)),
+            (
+                "side_wings_angle_of_attack",
+                "rad",
+                Continuous,
+                constant(10),
+            ),
+            ("rear_tire_pressure", "bar", Continuous, constant(11)),
+            ("front_tire_pressure", "bar", Continuous, constant(12)),
+            ("cabin_height", "m", Continuous, constant(13)),
+            ("cabin_length", "m", Continuous, constant(14)),
+            ("cabin_width", "m", Continuous, constant(15)),
+            ("cabin_thickness", "m", Continuous, constant(16)),
+            ("impact_attenuator_height", "m", Continuous, constant(17)),
+            ("impact_attenuator_width", "m", Continuous, constant(18)),
+            (
+                "rear_wing_material_index",
+                "",
+                Categorical,
+                index(materials),
+            ),
+            (
+                "front_wing_material_index",
+                "",
+                Categorical,
+                index(materials),
+            ),
+            (
+                "side_wing_material_index",
+                "",
+                Categorical,
+                index(materials),
+            ),
+            ("cabin_material_index", "", Categorical, index(materials)),
+            (
+                "impact_attenuator_material_index",
+                "",
+                Categorical,
+                index(materials),
+            ),
+            (
+                "rear_tire_index",
+                "",
+                Categorical,
+                index(catalog.tires().len()),
+            ),
+            (
+                "front_tire_index",
+                "",
+                Categorical,
+                index(catalog.tires().len()),
+            ),
+            (
+                "brake_index",
+                "",
+                Categorical,
+                index(catalog.brakes().len()),
+            ),
+            ("motor_index", "", Categorical, index(motors)),
+            (
+                if variant.independent_suspension {
+                    "rear_suspension_index"
+                } else {
+                    "suspension_index"
+                },
+                "",
+                Categorical,
+                index(suspensions),
+            ),
+            ("rear_wing_width", "m", Continuous, None),
+            ("rear_wing_y_position", "m", Continuous, None),
+            ("front_wing_y_position", "m", Continuous, None),
+            ("side_wing_y_position", "m", Continuous, None),
+            ("engine_y_position", "m", Continuous, None),
+            ("cabin_y_position", "m", Continuous, None),
+            ("impact_attenuator_length", "m", Continuous, None),
+            ("impact_attenuator_y_position", "m", Continuous, None),
+            ("rear_suspension_y_position", "m", Continuous, None),
+            ("front_suspension_y_position", "m", Continuous, None),
+        ];
+        if variant.independent_suspension {
+            entries.push((
+                "front_suspension_index",
+                "",
+                Categorical,
+                index(suspensions),
+            ));
+        }
+        if variant.variable_final_drive {
+            entries.push((
+                "final_drive_ratio",
+                "",
+                Continuous,
+                Some(constants::FINAL_DRIVE_BOUNDS),
+            ));
+        }
+        if electric {
+            entries.extend(vec![
+                ("cell_index", "", Categorical, index(catalog.cells().len())),
+                (
+                    "inverter_index",
+                    "",
+                    Categorical,
+                    index(catalog.inverters().len()),
+                ),
+                (
+                    "cells_in_series",
+                    "",
+                    Integer,
+                    Some(constants::CELLS_IN_SERIES_BOUNDS),
+                ),
+                (
+                    "cells_in_parallel",
+                    "",
+                    Integer,
+                    Some(constants::CELLS_IN_PARALLEL_BOUNDS),
+                ),
+            ]);
+        }
+
+        ParameterSchema {
+            parameters: entries
+                .into_iter()
+                .enumerate()
+                .map(|(index, (name, unit, kind, bounds))| Parameter {
+                    name,
+                    index,
+                    unit,
+                    kind,
+                    bounds,
+                })
+                .collect(),
+        }
+    }
+
+    /// Every entry, in parameter vector order
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.parameters
+    }
+
+    /// The number of entries in the parameter vector
+    pub fn len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+
+    /// The entry with the given name
+    pub fn get(&self, name: &str) -> Option<&Parameter> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+    }
+
+    /// The position of the entry with the given name
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.get(name).map(|parameter| parameter.index)
+    }
+
+    /// The value of the named entry in a parameter vector laid out by this schema
+    ///
+    /// # Panics
+    /// If there is no entry with that name.
+    pub(crate) fn value(&self, p: &[f64], name: &str) -> f64 {
+        match self.index_of(name) {
+            Some(index) => p[index],
+            None => panic!("no parameter named {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        let catalog = Catalog::builtin();
+        let schema = ParameterSchema::new(&catalog, ProblemVariant::ORIGINAL);
+        assert_eq!(schema.len(), ProblemVariant::ORIGINAL.parameter_count());
+        assert_eq!(schema.index_of("rear_wing_height"), Some(0));
+        assert_eq!(schema.index_of("rear_wing_length"), Some(1));
+        assert_eq!(schema.get("motor_index").unwrap().bounds, Some([0.0, 20.0]));
+        assert_eq!(schema.index_of("front_suspension_index"), None);
+
+        let variant = ProblemVariant {
+            independent_suspension: true,
+            ..ProblemVariant::ORIGINAL
+        };
+        let schema = ParameterSchema::new(&catalog, variant);
+        assert_eq!(schema.len(), variant.parameter_count());
+        assert_eq!(schema.index_of("front_suspension_index"), Some(39));
+
+        let variant = ProblemVariant {
+            independent_suspension: true,
+            variable_final_drive: true,
+            ..ProblemVariant::ORIGINAL
+        };
+        let schema = ParameterSchema::new(&catalog, variant);
+        assert_eq!(schema.len(), variant.parameter_count());
+        assert_eq!(schema.index_of("final_drive_ratio"), Some(40));
+
+        let variant = ProblemVariant {
+            powertrain: Powertrain::Electric,
+            ..ProblemVariant::ORIGINAL
+        };
+        let schema = ParameterSchema::new(&catalog, variant);
+        assert_eq!(schema.len(), variant.parameter_count());
+        assert_eq!(schema.get("motor_index").unwrap().bounds, Some([0.0, 4.0]));
+        assert_eq!(schema.index_of("cells_in_parallel"), Some(42));
+    }
+}
@@ -0,0 +1,141 @@
+//! This contains the evaluation of speed-dependent quantities across a range of speeds
+
+use uom::si::f64::{Acceleration, Force, Velocity};
+
+use crate::{Car, OperatingConditions};
+
+/// The speed-dependent quantities of a car at one speed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpeedPoint {
+    pub speed: Velocity,
+    pub drag_force: Force,
+    pub downforce: Force,
+    pub rolling_resistance: Force,
+    /// The force delivered by the driven wheels
+    pub tractive_force: Force,
+    /// The tractive force less drag and rolling resistance
+    pub net_force: Force,
+    pub acceleration: Acceleration,
+}
+
+/// The speed-dependent quantities of a car over a range of speeds
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpeedSweep {
+    /// One point per speed, in increasing order of speed
+    pub points: Vec<SpeedPoint>,
+}
+
+impl SpeedSweep {
+    /// The lowest swept speed at which the tractive force no longer exceeds the resistance,
+    /// interpolated between the neighbouring points
+    ///
+    /// This is `None` if the car can still accelerate at the highest swept speed.
+    pub fn top_speed(&self) -> Option<Velocity> {
+        if let Some(first) = self.points.first() {
+            if first.net_force.value <= 0.0 {
+                return Some(first.speed);
+            }
+        }
+        self.points.windows(2).find_map(|pair| {
+            let (a, b) = (&pair[0], &pair[1]);
+            if b.net_force.value > 0.0 {
+                return None;
+            }
+            let fraction = a.net_force.value / (a.net_force.value - b.net_force.value);
+            Some(a.speed + (b.speed - a.speed) * fraction)
+        })
+    }
+}
+
+impl Car {
+    /// A copy of this car evaluated at a different speed, with all other conditions unchanged
+    pub fn at_speed(&self, speed: Velocity) -> Car {
+        self.clone().with_conditions(OperatingConditions {
+            vehicle_speed: speed,
+            ..self.conditions
+        })
+    }
+
+    /// Evaluate the speed-dependent quantities at `steps` evenly spaced speeds from `from` to
+    /// `to`, inclusive
+    pub fn speed_sweep(&self, from: Velocity, to: Velocity, steps: usize) -> SpeedSweep {
+        let points = (0..steps)
+            .map(|i| {
+                let fraction = if steps > 1 {
+                    i as f64 / (steps - 1) as f64
+                } else {
+                    0.0
+                };
+                let car = self.at_speed(from + (to - from) * fraction);
+                let drag_force = car.total_drag_force();
+                let rolling_resistance = car.rolling_resistance(car.rear_tire_pressure);
+                let tractive_force = car.tractive_force();
+                let net_force = tractive_force - (drag_force + rolling_resistance);
+                SpeedPoint {
+                    speed: car.conditions.vehicle_speed,
+                    drag_force,
+                    downforce: car.total_downward_force(),
+                    rolling_resistance,
+                    tractive_force,
+                    net_force,
+                    acceleration: net_force / car.mass(),
+                }
+            })
+            .collect();
+        SpeedSweep { points }
+    }
+
+    /// The speed at which the tractive force equals drag and rolling resistance, searched for
+    /// between standstill and `limit`
+    pub fn top_speed(&self, limit: Velocity) -> Option<Velocity> {
+        self.speed_sweep(limit * 0.01, limit, 200).top_speed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use uom::si::{force::newton, velocity::meter_per_second};
+
+    #[test]
+    fn sweep() {
+        let car = Car::new_with_rng(&mut StdRng::seed_from_u64(0));
+        let from = Velocity::new::<meter_per_second>(10.0);
+        let to = Velocity::new::<meter_per_second>(20.0);
+        let sweep = car.speed_sweep(from, to, 11);
+        assert_eq!(sweep.points.len(), 11);
+        assert_eq!(sweep.points[0].speed, from);
+        assert_eq!(sweep.points[10].speed, to);
+
+        let ratio = sweep.points[10].downforce / sweep.points[0].downforce;
+        assert!((ratio.value - 4.0).abs() < 1e-9);
+        let at_speed = car.at_speed(to);
+        assert_eq!(sweep.points[10].acceleration, at_speed.acceleration());
+    }
+
+    #[test]
+    fn top_speed() {
+        let point = |speed: f64, net_force: f64| SpeedPoint {
+            speed: Velocity::new::<meter_per_second>(speed),
+            drag_force: Force::default(),
+            downforce: Force::default(),
+            rolling_resistance: Force::default(),
+            tractive_force: Force::default(),
+            net_force: Force::new::<newton>(net_force),
+            acceleration: Acceleration::default(),
+        };
+        let sweep = SpeedSweep {
+            points: vec![point(10.0, 300.0), point(20.0, 100.0), point(30.0, -100.0)],
+        };
+        assert_eq!(
+            sweep.top_speed(),
+            Some(Velocity::new::<meter_per_second>(25.0))
+        );
+
+        let sweep = SpeedSweep {
+            points: vec![point(10.0, 300.0), point(20.0, 100.0)],
+        };
+        assert_eq!(sweep.top_speed(), None);
+    }
+}
@@ -0,0 +1,176 @@
+//! This contains the reader shared by catalog and track files, which holds a CSV or JSON file as
+//! text cells
+
+use std::path::Path;
+
+/// A CSV or JSON file held as text cells, addressed by row number and column header
+///
+/// A JSON file is an array of flat objects whose keys are the column headers. An object that
+/// leaves out a key, or gives it as `null`, has an empty cell in that column, as does an empty
+/// cell of a CSV file.
+pub(crate) struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+/// The reasons a file can fail to be read as a table
+#[derive(Debug)]
+pub(crate) enum TableError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file extension is neither `.csv` nor `.json`
+    UnsupportedFormat(String),
+    /// The file is not valid JSON, or not an array of flat objects
+    Json(String),
+    /// A row has a different number of cells than the header
+    RowLength { row: usize },
+}
+
+impl From<std::io::Error> for TableError {
+    fn from(error: std::io::Error) -> Self {
+        TableError::Io(error)
+    }
+}
+
+impl Table {
+    /// Read a `.csv` or `.json` file, chosen by its extension
+    pub(crate) fn read(path: &Path) -> Result<Self, TableError> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("csv") => Table::parse_csv(&std::fs::read_to_string(path)?),
+            Some("json") => Table::parse_json(&std::fs::read_to_string(path)?),
+            _ => Err(TableError::UnsupportedFormat(path.display().to_string())),
+        }
+    }
+
+    pub(crate) fn parse_csv(text: &str) -> Result<Self, TableError> {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+        let headers: Vec<String> = lines
+            .next()
+            .map(split_record)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|header| header.trim().to_owned())
+            .collect();
+        let rows: Vec<Vec<String>> = lines.map(split_record).collect();
+        if let Some(row) = rows.iter().position(|cells| cells.len() != headers.len()) {
+            return Err(TableError::RowLength { row });
+        }
+        Ok(Table { headers, rows })
+    }
+
+    pub(crate) fn parse_json(text: &str) -> Result<Self, TableError> {
+        let value: serde_json::Value =
+            serde_json::from_str(text).map_err(|error| TableError::Json(error.to_string()))?;
+        let objects: Vec<&serde_json::Map<String, serde_json::Value>> = value
+            .as_array()
+            .and_then(|entries| entries.iter().map(|entry| entry.as_object()).collect())
+            .ok_or_else(|| TableError::Json("expected an array of objects".to_owned()))?;
+
+        let mut headers: Vec<String> = vec![];
+        for object in &objects {
+            for key in object.keys() {
+                if !headers.contains(key) {
+                    headers.push(key.clone());
+                }
+            }
+        }
+
+        let mut rows = vec![];
+        for (row, object) in objects.iter().enumerate() {
+            let mut cells = vec![];
+            for header in &headers {
+                cells.push(match object.get(header) {
+                    Some(serde_json::Value::String(text)) => text.clone(),
+                    Some(serde_json::Value::Number(number)) => number.to_string(),
+                    Some(serde_json::Value::Null) | None => String::new(),
+                    Some(_) => {
+                        return Err(TableError::Json(format!(
+                            "`{}` in entry {} must be a string or a number",
+                            header, row
+                        )))
+                    }
+                });
+            }
+            rows.push(cells);
+        }
+        Ok(Table { headers, rows })
+    }
+
+    /// The number of rows below the header
+    pub(crate) fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub(crate) fn has(&self, column: &str) -> bool {
+        self.headers.iter().any(|header| header == column)
+    }
+
+    /// The text of a cell, or `None` if the column is absent
+    pub(crate) fn cell(&self, row: usize, column: &str) -> Option<&str> {
+        let index = self.headers.iter().position(|header| header == column)?;
+        Some(&self.rows[row][index])
+    }
+}
+
+/// Split one line of a CSV file into cells, respecting double-quoted cells
+fn split_record(line: &str) -> Vec<String> {
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => cells.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell);
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        let csv = Table::parse_csv("id, radius,note\nX1,0.25,\"a, b\"\nX2,0.3,\n").unwrap();
+        let json = Table::parse_json(
+            r#"[{"id": "X1", "radius": 0.25, "note": "a, b"}, {"id": "X2", "radius": 0.3}]"#,
+        )
+        .unwrap();
+        for table in &[&csv, &json] {
+            assert_eq!(table.len(), 2);
+            assert!(table.has("radius"));
+            assert_eq!(table.cell(0, "note"), Some("a, b"));
+            assert_eq!(table.cell(1, "note"), Some(""));
+            assert_eq!(table.cell(1, "mass"), None);
+        }
+
+        assert!(matches!(
+            Table::parse_csv("id,radius\nX1\n"),
+            Err(TableError::RowLength { row: 0 })
+        ));
+        assert!(matches!(
+            Table::parse_json(r#"[{"id": [1]}]"#),
+            Err(TableError::Json(_))
+        ));
+        assert!(matches!(
+            Table::read(Path::new("table.txt")),
+            Err(TableError::UnsupportedFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,456 @@
+//! This contains the description of a track as a sequence of segments, and its file formats
+
+use std::path::Path;
+
+use crate::table::{Table, TableError};
+
+use uom::si::{f64::Length, length::meter};
+
+const AUTOCROSS: &str = include_str!("data/tracks/autocross.csv");
+const ENDURANCE: &str = include_str!("data/tracks/endurance.csv");
+const OVAL: &str = include_str!("data/tracks/oval.json");
+
+/// Curvatures below this, in 1/m, are treated as straight when reading a centerline
+const STRAIGHT_CURVATURE: f64 = 1e-3;
+
+/// The way a corner turns
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// A piece of track of constant curvature
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment {
+    Straight {
+        length: Length,
+        /// The rise from the start to the end of the segment
+        elevation_change: Length,
+    },
+    /// A corner of constant radius, with `length` measured along the arc
+    Corner {
+        radius: Length,
+        length: Length,
+        direction: Direction,
+        elevation_change: Length,
+    },
+}
+
+impl Segment {
+    /// The distance along the segment
+    pub fn length(&self) -> Length {
+        match self {
+            Segment::Straight { length, .. } | Segment::Corner { length, .. } => *length,
+        }
+    }
+
+    /// The radius of the segment, or `None` for a straight
+    pub fn radius(&self) -> Option<Length> {
+        match self {
+            Segment::Straight { .. } => None,
+            Segment::Corner { radius, .. } => Some(*radius),
+        }
+    }
+
+    /// The way the segment turns, or `None` for a straight
+    pub fn direction(&self) -> Option<Direction> {
+        match self {
+            Segment::Straight { .. } => None,
+            Segment::Corner { direction, .. } => Some(*direction),
+        }
+    }
+
+    /// The rise from the start to the end of the segment
+    pub fn elevation_change(&self) -> Length {
+        match self {
+            Segment::Straight {
+                elevation_change, ..
+            }
+            | Segment::Corner {
+                elevation_change, ..
+            } => *elevation_change,
+        }
+    }
+
+    /// The sine of the slope, positive uphill, or zero for a segment of no length
+    pub fn grade(&self) -> f64 {
+        if self.length() <= Length::default() {
+            return 0.0;
+        }
+        (self.elevation_change() / self.length())
+            .value
+            .clamp(-1.0, 1.0)
+    }
+}
+
+/// A track driven from the start of its first segment to the end of its last
+///
+/// Tracks can be read from CSV or JSON files in one of two layouts. A list of segments has the
+/// columns `length` and optionally `radius`, `direction` (`left` or `right`) and `elevation`,
+/// with an empty or zero radius for a straight. A centerline has the columns `x`, `y` and
+/// optionally `z`, from which the curvature of each segment is computed. All values are in
+/// meters, and a JSON file is an array of objects with the same keys.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Track {
+    pub segments: Vec<Segment>,
+}
+
+impl Track {
+    pub fn new(segments: Vec<Segment>) -> Self {
+        Track { segments }
+    }
+
+    /// The total distance along the track
+    pub fn length(&self) -> Length {
+        self.segments
+            .iter()
+            .fold(Length::default(), |total, segment| total + segment.length())
+    }
+
+    /// A flat straight of the given length, such as the 75 m acceleration event
+    pub fn straight(length: Length) -> Self {
+        Track::new(vec![Segment::Straight {
+            length,
+            elevation_change: Length::default(),
+        }])
+    }
+
+    /// A sample autocross layout of about 600 m, bundled as `data/tracks/autocross.csv`
+    pub fn autocross() -> Self {
+        Track::parse_csv(AUTOCROSS).expect("bundled autocross track is valid")
+    }
+
+    /// A sample endurance lap of 1 km, bundled as `data/tracks/endurance.csv`
+    pub fn endurance_lap() -> Self {
+        Track::parse_csv(ENDURANCE).expect("bundled endurance track is valid")
+    }
+
+    /// A sample oval with 100 m straights and 20 m radius ends, bundled as a centerline in
+    /// `data/tracks/oval.json`
+    pub fn oval() -> Self {
+        Track::parse_json(OVAL).expect("bundled oval track is valid")
+    }
+
+    /// Read a track from a `.csv` or `.json` file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TrackError> {
+        track_from(&Table::read(path.as_ref())?)
+    }
+
+    /// Parse a track from the text of a CSV file
+    pub fn parse_csv(text: &str) -> Result<Self, TrackError> {
+        track_from(&Table::parse_csv(text)?)
+    }
+
+    /// Parse a track from the text of a JSON file
+    pub fn parse_json(text: &str) -> Result<Self, TrackError> {
+        track_from(&Table::parse_json(text)?)
+    }
+
+    /// Build a track from centerline points `[x, y, z]` in meters
+    ///
+    /// Each pair of neighbouring points becomes one segment, with the curvature averaged from
+    /// the circles through each point and its neighbours. Repeated points are dropped, and a
+    /// centerline whose last point coincides with its first is treated as a closed loop.
+    pub fn from_centerline(points: &[[f64; 3]]) -> Result<Self, TrackError> {
+        let mut points = points.to_vec();
+        points.dedup_by(|b, a| distance(a, b) < 1e-9);
+        let points = &points[..];
+        if points.len() < 2 {
+            return Err(TrackError::Empty);
+        }
+        let n = points.len();
+        let closed = n > 3 && distance(&points[0], &points[n - 1]) < 1e-9;
+
+        let curvature = |i: usize| -> f64 {
+            let (previous, next) = match (i, closed) {
+                (0, true) => (n - 2, 1),
+                (i, true) if i == n - 1 => (n - 2, 1),
+                (0, false) => return 0.0,
+                (i, false) if i == n - 1 => return 0.0,
+                (i, _) => (i - 1, i + 1),
+            };
+            signed_curvature(&points[previous], &points[i], &points[next])
+        };
+        let curvatures: Vec<f64> = (0..n).map(curvature).collect();
+
+        let segments = points
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let length = Length::new::<meter>(distance(&pair[0], &pair[1]));
+                let elevation_change = Length::new::<meter>(pair[1][2] - pair[0][2]);
+                let kappa = 0.5 * (curvatures[i] + curvatures[i + 1]);
+                if kappa.abs() < STRAIGHT_CURVATURE {
+                    Segment::Straight {
+                        length,
+                        elevation_change,
+                    }
+                } else {
+                    Segment::Corner {
+                        radius: Length::new::<meter>(1.0 / kappa.abs()),
+                        length,
+                        direction: if kappa > 0.0 {
+                            Direction::Left
+                        } else {
+                            Direction::Right
+                        },
+                        elevation_change,
+                    }
+                }
+            })
+            .collect();
+        Ok(Track::new(segments))
+    }
+}
+
+/// The horizontal distance between two points
+fn distance(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    (b[0] - a[0]).hypot(b[1] - a[1])
+}
+
+/// The curvature of the circle through three points, positive when turning left
+fn signed_curvature(a: &[f64; 3], b: &[f64; 3], c: &[f64; 3]) -> f64 {
+    let cross = (b[0] - a[0]) * (c[1] - b[1]) - (b[1] - a[1]) * (c[0] - b[0]);
+    let lengths = distance(a, b) * distance(b, c) * distance(a, c);
+    if lengths > 0.0 {
+        2.0 * cross / lengths
+    } else {
+        0.0
+    }
+}
+
+/// Build a track from the rows of a file, either as centerline points in the columns `x`, `y`
+/// and `z` or as one segment per row
+fn track_from(table: &Table) -> Result<Track, TrackError> {
+    if table.is_empty() {
+        return Err(TrackError::Empty);
+    }
+    if table.has("x") || table.has("y") {
+        let points = (0..table.len())
+            .map(|row| {
+                Ok([
+                    number(table, row, "x")?.unwrap_or_default(),
+                    number(table, row, "y")?.unwrap_or_default(),
+                    number(table, row, "z")?.unwrap_or_default(),
+                ])
+            })
+            .collect::<Result<Vec<_>, TrackError>>()?;
+        return Track::from_centerline(&points);
+    }
+    if !table.has("length") {
+        return Err(TrackError::MissingColumn("length".to_owned()));
+    }
+    (0..table.len())
+        .map(|row| segment_from(table, row))
+        .collect::<Result<Vec<_>, TrackError>>()
+        .map(Track::new)
+}
+
+fn segment_from(table: &Table, row: usize) -> Result<Segment, TrackError> {
+    let length = match number(table, row, "length")? {
+        Some(length) if length > 0.0 => Length::new::<meter>(length),
+        _ => {
+            return Err(TrackError::NonPositive {
+                row,
+                column: "length",
+            })
+        }
+    };
+    let elevation_change = Length::new::<meter>(number(table, row, "elevation")?.unwrap_or(0.0));
+    match number(table, row, "radius")?.filter(|radius| *radius != 0.0) {
+        None => Ok(Segment::Straight {
+            length,
+            elevation_change,
+        }),
+        Some(radius) if radius < 0.0 => Err(TrackError::NonPositive {
+            row,
+            column: "radius",
+        }),
+        Some(radius) => {
+            let direction = table.cell(row, "direction").unwrap_or_default();
+            let direction = match direction.trim().to_ascii_lowercase().as_str() {
+                "left" | "l" => Direction::Left,
+                "right" | "r" => Direction::Right,
+                _ => {
+                    return Err(TrackError::InvalidDirection {
+                        row,
+                        value: direction.to_owned(),
+                    })
+                }
+            };
+            Ok(Segment::Corner {
+                radius: Length::new::<meter>(radius),
+                length,
+                direction,
+                elevation_change,
+            })
+        }
+    }
+}
+
+/// The number in a cell, or `None` if the column is absent or the cell is empty
+fn number(table: &Table, row: usize, column: &'static str) -> Result<Option<f64>, TrackError> {
+    let value = match table.cell(row, column) {
+        Some(value) if !value.trim().is_empty() => value,
+        _ => return Ok(None),
+    };
+    match value.trim().parse::<f64>() {
+        Ok(number) if number.is_finite() => Ok(Some(number)),
+        _ => Err(TrackError::InvalidNumber {
+            row,
+            column,
+            value: value.to_owned(),
+        }),
+    }
+}
+
+/// The reasons a track file can be rejected
+#[derive(Debug)]
+pub enum TrackError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file extension is neither `.csv` nor `.json`
+    UnsupportedFormat(String),
+    /// The file is not valid JSON, or not an array of flat objects
+    Json(String),
+    /// The file lists no segments, or fewer than two distinct centerline points
+    Empty,
+    /// A row has a different number of cells than the header
+    RowLength { row: usize },
+    /// A list of segments has no `length` column
+    MissingColumn(String),
+    /// A cell that should hold a number does not
+    InvalidNumber {
+        row: usize,
+        column: &'static str,
+        value: String,
+    },
+    /// A length or radius is negative, or a length is zero
+    NonPositive { row: usize, column: &'static str },
+    /// A corner is not marked `left` or `right`
+    InvalidDirection { row: usize, value: String },
+}
+
+impl std::fmt::Display for TrackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrackError::Io(error) => write!(f, "could not read track: {}", error),
+            TrackError::UnsupportedFormat(path) => {
+                write!(f, "`{}` is not a .csv or .json file", path)
+            }
+            TrackError::Json(message) => write!(f, "invalid JSON track: {}", message),
+            TrackError::Empty => write!(f, "track has no segments"),
+            TrackError::RowLength { row } => {
+                write!(f, "row {} has the wrong number of cells", row)
+            }
+            TrackError::MissingColumn(column) => {
+                write!(f, "track has no column named `{}`", column)
+            }
+            TrackError::InvalidNumber { row, column, value } => write!(
+                f,
+                "`{}` in row {}, column `{}` is not a number",
+                value, row, column
+            ),
+            TrackError::NonPositive { row, column } => {
+                write!(f, "row {}, column `{}` must be positive", row, column)
+            }
+            TrackError::InvalidDirection { row, value } => write!(
+                f,
+                "corner in row {} turns `{}`, not `left` or `right`",
+                row, value
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TrackError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TrackError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for TrackError {
+    fn from(error: std::io::Error) -> Self {
+        TrackError::Io(error)
+    }
+}
+
+impl From<TableError> for TrackError {
+    fn from(error: TableError) -> Self {
+        match error {
+            TableError::Io(error) => TrackError::Io(error),
+            TableError::UnsupportedFormat(path) => TrackError::UnsupportedFormat(path),
+            TableError::Json(message) => TrackError::Json(message),
+            TableError::RowLength { row } => TrackError::RowLength { row },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled() {
+        let autocross = Track::autocross();
+        assert_eq!(autocross.segments.len(), 12);
+        assert!((autocross.length().get::<meter>() - 603.0).abs() < 1e-9);
+        assert_eq!(autocross.segments[1].direction(), Some(Direction::Left));
+        assert!((Track::endurance_lap().length().get::<meter>() - 1000.0).abs() < 1e-9);
+
+        let oval = Track::oval();
+        let length = oval.length().get::<meter>();
+        assert!((length - (200.0 + 2.0 * std::f64::consts::PI * 20.0)).abs() < 1.0);
+        let tightest = oval
+            .segments
+            .iter()
+            .filter_map(|segment| segment.radius())
+            .map(|radius| radius.get::<meter>())
+            .fold(f64::INFINITY, f64::min);
+        assert!((tightest - 20.0).abs() < 0.5);
+        assert!(oval
+            .segments
+            .iter()
+            .all(|segment| segment.direction() != Some(Direction::Right)));
+    }
+
+    #[test]
+    fn formats() {
+        let csv = Track::parse_csv("length,radius,direction\n10,,\n5,8,Right\n").unwrap();
+        let json = Track::parse_json(
+            r#"[{"length": 10}, {"length": 5, "radius": 8, "direction": "right"}]"#,
+        )
+        .unwrap();
+        assert_eq!(csv, json);
+        assert_eq!(csv.segments[1].radius(), Some(Length::new::<meter>(8.0)));
+
+        assert!(matches!(
+            Track::parse_csv("length,radius\n5,8\n"),
+            Err(TrackError::InvalidDirection { row: 0, .. })
+        ));
+        assert!(matches!(
+            Track::parse_csv("radius\n8\n"),
+            Err(TrackError::MissingColumn(_))
+        ));
+        let repeated = [
+            [0.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [10.0, 0.0, 0.0],
+            [20.0, 0.0, 0.0],
+        ];
+        let track = Track::from_centerline(&repeated).unwrap();
+        assert_eq!(track.segments.len(), 2);
+        assert!(matches!(
+            Track::from_centerline(&[[1.0, 2.0, 0.0], [1.0, 2.0, 3.0]]),
+            Err(TrackError::Empty)
+        ));
+        assert!(matches!(
+            Track::from_file("track.txt"),
+            Err(TrackError::UnsupportedFormat(_))
+        ));
+    }
+}
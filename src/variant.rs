@@ -0,0 +1,45 @@
+//! This contains the switches between formulations of the design problem
+
+/// The source of tractive power
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Powertrain {
+    /// A combustion engine from `Catalog::motors`
+    #[default]
+    Combustion,
+    /// An electric motor from `Catalog::electric_motors`, fed through an inverter by a battery
+    /// pack
+    Electric,
+}
+
+/// Selects which formulation of the design problem a car belongs to
+///
+/// The default is the original 39-variable problem.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProblemVariant {
+    /// Choose the rear and front suspensions separately, appending the front suspension index as
+    /// an extra design variable after the original 39
+    pub independent_suspension: bool,
+    /// Make the final drive ratio a design variable, appended after every other variable,
+    /// instead of fixing it at 2.85
+    pub variable_final_drive: bool,
+    /// Choose between a combustion and an electric powertrain. The electric powertrain appends
+    /// the cell index, inverter index and the number of cells in series and in parallel as design
+    /// variables after every other variable.
+    pub powertrain: Powertrain,
+}
+
+impl ProblemVariant {
+    /// The original 39-variable problem
+    pub const ORIGINAL: ProblemVariant = ProblemVariant {
+        independent_suspension: false,
+        variable_final_drive: false,
+        powertrain: Powertrain::Combustion,
+    };
+
+    /// The number of entries in a parameter vector for this variant
+    pub fn parameter_count(&self) -> usize {
+        39 + self.independent_suspension as usize
+            + self.variable_final_drive as usize
+            + 4 * (self.powertrain == Powertrain::Electric) as usize
+    }
+}
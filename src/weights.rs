@@ -0,0 +1,38 @@
+//! This contains the weightings of the objectives used in the published problem variants
+
+const EVEN_WEIGHTS: [f64; 11] = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0];
+const WEIGHTS1: [f64; 11] = [14.0, 1.0, 20.0, 30.0, 10.0, 1.0, 1.0, 10.0, 10.0, 2.0, 1.0];
+const WEIGHTS2: [f64; 11] = [25.0, 1.0, 15.0, 20.0, 15.0, 1.0, 1.0, 15.0, 5.0, 1.0, 1.0];
+const WEIGHTS3: [f64; 11] = [14.0, 1.0, 20.0, 15.0, 25.0, 1.0, 1.0, 10.0, 10.0, 2.0, 1.0];
+
+/// The weights given to each of the 11 objectives in the weighted sum of `Car::objective`
+///
+/// The weights apply in the order of `Objectives::NAMES`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WeightScenario {
+    /// Every objective weighted equally
+    #[default]
+    Even,
+    Scenario1,
+    Scenario2,
+    Scenario3,
+    Custom([f64; 11]),
+}
+
+impl WeightScenario {
+    pub fn weights(&self) -> [f64; 11] {
+        match self {
+            WeightScenario::Even => EVEN_WEIGHTS,
+            WeightScenario::Scenario1 => WEIGHTS1,
+            WeightScenario::Scenario2 => WEIGHTS2,
+            WeightScenario::Scenario3 => WEIGHTS3,
+            WeightScenario::Custom(weights) => *weights,
+        }
+    }
+}
+
+impl From<[f64; 11]> for WeightScenario {
+    fn from(weights: [f64; 11]) -> Self {
+        WeightScenario::Custom(weights)
+    }
+}